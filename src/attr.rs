@@ -1,39 +1,99 @@
-use std::collections::HashMap;
+use std::str::FromStr;
 
 use quick_xml::events::BytesStart;
 
 use crate::error::Error;
 
+#[cfg(feature = "attr-presentation")]
+use self::{color::Color, paint::Paint, transform::Transform};
+
+#[cfg(feature = "attr-aria")]
+use self::aria::Role;
+
+#[cfg(feature = "crossorigin")]
+pub mod cross_origin;
 #[cfg(feature = "crossorigin")]
 pub mod referrer_policy;
 
+#[cfg(feature = "attr-aria")]
+pub mod aria;
+
+pub mod animation;
+pub mod color;
+pub mod decoding;
+pub mod filter;
+pub mod length;
 pub mod length_adjust;
+pub mod marker;
+pub mod paint;
+pub mod spread_method;
+pub mod text_path;
+pub mod transform;
+pub mod units;
+pub mod view_box;
 
 pub trait WriteInAttr {
-    fn write_in(&self, bs: &mut BytesStart) -> Result<(), Error>;
+    fn write_in(&self, bs: &mut BytesStart, order: AttrOrder) -> Result<(), Error>;
 }
 
 pub trait AttrKey {
     fn attr_key(&self) -> &str;
 }
 
-impl<K> WriteInAttr for HashMap<K, String>
-where
-    K: AttrKey,
-{
-    fn write_in(&self, bs: &mut BytesStart) -> Result<(), Error> {
-        for (key, val) in self.iter() {
-            bs.push_attribute((key.attr_key(), val.as_str()));
+pub trait AsValue {
+    fn as_value(&self) -> &str;
+}
+
+/// Internal helper macro for defining a keyword attribute value,
+/// along with its parsing and serialization.
+macro_rules! def_keyword {
+    {
+        $(#[$meta:meta])*
+        $name:ident {
+            $(
+                $(#[$entry_meta:meta])*
+                $entry:ident => $value:literal,
+            )*
+        }
+    } => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$entry_meta])*
+                $entry,
+            )*
         }
 
-        Ok(())
-    }
-}
+        impl std::str::FromStr for $name {
+            type Err = crate::error::Error;
 
-pub trait AsValue {
-    fn as_value(&self) -> &str;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($value => Ok(Self::$entry),)*
+                    _ => Err(crate::error::Error::NoOptionFound),
+                }
+            }
+        }
+
+        impl crate::attr::AsValue for $name {
+            fn as_value(&self) -> &str {
+                match self {
+                    $(Self::$entry => $value,)*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(crate::attr::AsValue::as_value(self))
+            }
+        }
+    };
 }
 
+pub(crate) use def_keyword;
+
 macro_rules! def_sparse_attr {
     {
         [$type_name:ident]
@@ -57,7 +117,7 @@ macro_rules! def_sparse_attr {
         /// SVG Attributes
         ///
         /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute).
-        #[derive(PartialEq, Eq, Hash)]
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub enum $type_name {
             $($(
                 $(#[$entry_macro])*
@@ -82,6 +142,8 @@ macro_rules! def_sparse_attr {
                     $entry_c,
                 )*
             )*
+            /// Any other attribute, written with its key as is.
+            Custom(String),
         }
 
         impl AttrKey for $type_name {
@@ -96,13 +158,33 @@ macro_rules! def_sparse_attr {
                         $(#[$entry_macro_c])*
                         Self::$entry_c => $attr_c,
                     )*)*
+                    Self::Custom(key) => key.as_str(),
+                }
+            }
+        }
+
+        impl From<&str> for $type_name {
+            /// Look up the attribute by its key, falling back to
+            /// `Custom` if the key is unknown.
+            fn from(key: &str) -> Self {
+                match key {
+                    $($(
+                        $(#[$entry_macro])*
+                        $attr => Self::$entry,
+                    )*)?
+                    $($(
+                        #[$proc_macro]
+                        $(#[$entry_macro_c])*
+                        $attr_c => Self::$entry_c,
+                    )*)*
+                    _ => Self::Custom(key.to_string()),
                 }
             }
         }
 
-        impl ToString for $type_name {
-            fn to_string(&self) -> String {
-                self.attr_key().to_string()
+        impl std::fmt::Display for $type_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.attr_key())
             }
         }
     };
@@ -251,9 +333,201 @@ def_sparse_attr! {
         OnFocusIn, "onfocusin";
         OnFocusOut, "onfocusout";
     }
+    #[cfg(feature = "attr-aria")] {
+        Role, "role";
+        AriaActiveDescendant, "aria-activedescendant";
+        AriaAtomic, "aria-atomic";
+        AriaAutoComplete, "aria-autocomplete";
+        AriaBusy, "aria-busy";
+        AriaChecked, "aria-checked";
+        AriaColCount, "aria-colcount";
+        AriaColIndex, "aria-colindex";
+        AriaColSpan, "aria-colspan";
+        AriaControls, "aria-controls";
+        AriaCurrent, "aria-current";
+        AriaDescribedBy, "aria-describedby";
+        AriaDescription, "aria-description";
+        AriaDetails, "aria-details";
+        AriaDisabled, "aria-disabled";
+        AriaErrorMessage, "aria-errormessage";
+        AriaExpanded, "aria-expanded";
+        AriaFlowTo, "aria-flowto";
+        AriaHasPopup, "aria-haspopup";
+        AriaHidden, "aria-hidden";
+        AriaInvalid, "aria-invalid";
+        AriaKeyShortcuts, "aria-keyshortcuts";
+        AriaLabel, "aria-label";
+        AriaLabelledBy, "aria-labelledby";
+        AriaLevel, "aria-level";
+        AriaLive, "aria-live";
+        AriaModal, "aria-modal";
+        AriaMultiLine, "aria-multiline";
+        AriaMultiSelectable, "aria-multiselectable";
+        AriaOrientation, "aria-orientation";
+        AriaOwns, "aria-owns";
+        AriaPlaceholder, "aria-placeholder";
+        AriaPosInSet, "aria-posinset";
+        AriaPressed, "aria-pressed";
+        AriaReadOnly, "aria-readonly";
+        AriaRelevant, "aria-relevant";
+        AriaRequired, "aria-required";
+        AriaRoleDescription, "aria-roledescription";
+        AriaRowCount, "aria-rowcount";
+        AriaRowIndex, "aria-rowindex";
+        AriaRowSpan, "aria-rowspan";
+        AriaSelected, "aria-selected";
+        AriaSetSize, "aria-setsize";
+        AriaSort, "aria-sort";
+        AriaValueMax, "aria-valuemax";
+        AriaValueMin, "aria-valuemin";
+        AriaValueNow, "aria-valuenow";
+        AriaValueText, "aria-valuetext";
+    }
+}
+
+/// Order of the attributes of an element when written as XML
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AttrOrder {
+    /// Attributes specific to the element in a fixed order, followed by
+    /// the ones of its [`AttrMap`] in the order they have been first set
+    #[default]
+    Insertion,
+    /// Order of declaration in [`Attr`], which groups attributes by their
+    /// category in the specification, followed by other attributes by key
+    Spec,
+    /// Alphabetical order of the keys
+    Alphabetical,
+}
+
+impl AttrOrder {
+    /// Sort all the attributes written so far, whether specific to the
+    /// element or from its [`AttrMap`].
+    pub(crate) fn sort(&self, bs: &mut BytesStart) -> Result<(), Error> {
+        if *self == AttrOrder::Insertion {
+            return Ok(());
+        }
+
+        let mut attrs = bs.attributes()
+            .map(|attr| {
+                let attr = attr.map_err(|err| Error::XmlReaderError(err.into()))?;
+                let key = std::str::from_utf8(attr.key.as_ref()).map_err(Error::Utf8ParseError)?;
+
+                Ok((key.to_string(), attr.value.into_owned()))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        match self {
+            AttrOrder::Insertion => {}
+            AttrOrder::Spec => attrs.sort_by_cached_key(|(key, _)| Attr::from(key.as_str())),
+            AttrOrder::Alphabetical => attrs.sort_by(|a, b| a.0.cmp(&b.0)),
+        }
+
+        bs.clear_attributes();
+
+        // Values are already escaped
+        for (key, value) in attrs.iter() {
+            bs.push_attribute(quick_xml::events::attributes::Attribute {
+                key: quick_xml::name::QName(key.as_bytes()),
+                value: value.as_slice().into(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Attribute store preserving insertion order
+///
+/// Setting an existing attribute keeps its position.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AttrMap {
+    entries: Vec<(Attr, String)>,
+}
+
+impl AttrMap {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, attr: &Attr) -> Option<&String> {
+        self.entries.iter()
+            .find(|(key, _)| key == attr)
+            .map(|(_, value)| value)
+    }
+
+    #[inline]
+    pub fn contains(&self, attr: &Attr) -> bool {
+        self.get(attr).is_some()
+    }
+
+    /// Set an attribute, returning the previous value.
+    pub fn insert(&mut self, attr: Attr, value: String) -> Option<String> {
+        match self.entries.iter_mut().find(|(key, _)| *key == attr) {
+            Some((_, cur)) => Some(std::mem::replace(cur, value)),
+            None => {
+                self.entries.push((attr, value));
+                None
+            }
+        }
+    }
+
+    /// Remove an attribute, keeping the order of the others.
+    pub fn remove(&mut self, attr: &Attr) -> Option<String> {
+        let index = self.entries.iter().position(|(key, _)| key == attr)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    /// Iterate over attributes in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Attr, &String)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Collect attributes in the given order.
+    pub fn sorted(&self, order: AttrOrder) -> Vec<(&Attr, &String)> {
+        let mut entries: Vec<_> = self.iter().collect();
+
+        match order {
+            AttrOrder::Insertion => {}
+            AttrOrder::Spec => entries.sort_by(|a, b| a.0.cmp(b.0)),
+            AttrOrder::Alphabetical => entries.sort_by(|a, b| a.0.attr_key().cmp(b.0.attr_key())),
+        }
+
+        entries
+    }
 }
 
-pub type AttrMap = HashMap<Attr, String>;
+impl FromIterator<(Attr, String)> for AttrMap {
+    fn from_iter<T: IntoIterator<Item = (Attr, String)>>(iter: T) -> Self {
+        let mut map = Self::new();
+
+        for (attr, value) in iter {
+            map.insert(attr, value);
+        }
+
+        map
+    }
+}
+
+impl WriteInAttr for AttrMap {
+    fn write_in(&self, bs: &mut BytesStart, order: AttrOrder) -> Result<(), Error> {
+        for (key, val) in self.sorted(order) {
+            bs.push_attribute((key.attr_key(), val.as_str()));
+        }
+
+        Ok(())
+    }
+}
 
 pub type LazyAttrMap = Option<AttrMap>;
 
@@ -263,6 +537,182 @@ pub trait AccessAttr {
     fn set_attr(&mut self, attr: Attr, value: &str);
 
     fn pop_attr(&mut self, attr: &Attr) -> Option<String>;
+
+    /// Parse an attribute into a typed value.
+    fn attr_value<T>(&self, attr: &Attr) -> Option<Result<T, T::Err>>
+    where
+        T: FromStr,
+    {
+        self.attr(attr).map(str::parse)
+    }
+
+    /// Set an attribute from a typed value.
+    fn set_attr_value<T>(&mut self, attr: Attr, value: &T)
+    where
+        T: ToString,
+    {
+        self.set_attr(attr, &value.to_string())
+    }
+
+    /// Get `transform` as a typed transform list.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform).
+    #[cfg(feature = "attr-presentation")]
+    fn transform(&self) -> Option<Result<Transform, Error>> {
+        self.attr_value(&Attr::Transform)
+    }
+
+    /// Set `transform` from a typed transform list.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform).
+    #[cfg(feature = "attr-presentation")]
+    fn set_transform(&mut self, transform: &Transform) {
+        self.set_attr_value(Attr::Transform, transform)
+    }
+
+    /// Get `fill` as a typed paint.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill).
+    #[cfg(feature = "attr-presentation")]
+    fn fill(&self) -> Option<Result<Paint, Error>> {
+        self.attr_value(&Attr::Fill)
+    }
+
+    /// Set `fill` from a typed paint.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill).
+    #[cfg(feature = "attr-presentation")]
+    fn set_fill(&mut self, paint: &Paint) {
+        self.set_attr_value(Attr::Fill, paint)
+    }
+
+    /// Get `stroke` as a typed paint.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke).
+    #[cfg(feature = "attr-presentation")]
+    fn stroke(&self) -> Option<Result<Paint, Error>> {
+        self.attr_value(&Attr::Stroke)
+    }
+
+    /// Set `stroke` from a typed paint.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke).
+    #[cfg(feature = "attr-presentation")]
+    fn set_stroke(&mut self, paint: &Paint) {
+        self.set_attr_value(Attr::Stroke, paint)
+    }
+
+    /// Get `stop-color` as a typed color.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stop-color).
+    #[cfg(feature = "attr-presentation")]
+    fn stop_color(&self) -> Option<Result<Color, Error>> {
+        self.attr_value(&Attr::StopColor)
+    }
+
+    /// Set `stop-color` from a typed color.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stop-color).
+    #[cfg(feature = "attr-presentation")]
+    fn set_stop_color(&mut self, color: &Color) {
+        self.set_attr_value(Attr::StopColor, color)
+    }
+
+    /// Get `flood-color` as a typed color.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/flood-color).
+    #[cfg(feature = "attr-presentation")]
+    fn flood_color(&self) -> Option<Result<Color, Error>> {
+        self.attr_value(&Attr::FloodColor)
+    }
+
+    /// Set `flood-color` from a typed color.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/flood-color).
+    #[cfg(feature = "attr-presentation")]
+    fn set_flood_color(&mut self, color: &Color) {
+        self.set_attr_value(Attr::FloodColor, color)
+    }
+
+    /// Get `lighting-color` as a typed color.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/lighting-color).
+    #[cfg(feature = "attr-presentation")]
+    fn lighting_color(&self) -> Option<Result<Color, Error>> {
+        self.attr_value(&Attr::LightingColor)
+    }
+
+    /// Set `lighting-color` from a typed color.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/lighting-color).
+    #[cfg(feature = "attr-presentation")]
+    fn set_lighting_color(&mut self, color: &Color) {
+        self.set_attr_value(Attr::LightingColor, color)
+    }
+
+    /// Get `role` as a typed role.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles).
+    #[cfg(feature = "attr-aria")]
+    fn role(&self) -> Option<Result<Role, Error>> {
+        self.attr_value(&Attr::Role)
+    }
+
+    /// Set `role` from a typed role.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles).
+    #[cfg(feature = "attr-aria")]
+    fn set_role(&mut self, role: Role) {
+        self.set_attr_value(Attr::Role, &role)
+    }
+
+    /// Get `aria-hidden` as a boolean.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-hidden).
+    #[cfg(feature = "attr-aria")]
+    fn aria_hidden(&self) -> Option<Result<bool, std::str::ParseBoolError>> {
+        self.attr_value(&Attr::AriaHidden)
+    }
+
+    /// Set `aria-hidden` from a boolean.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-hidden).
+    #[cfg(feature = "attr-aria")]
+    fn set_aria_hidden(&mut self, hidden: bool) {
+        self.set_attr_value(Attr::AriaHidden, &hidden)
+    }
+
+    /// Get the `id`s listed in `aria-labelledby`.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-labelledby).
+    #[cfg(feature = "attr-aria")]
+    fn aria_labelledby(&self) -> Option<Vec<&str>> {
+        Some(self.attr(&Attr::AriaLabelledBy)?.split_ascii_whitespace().collect())
+    }
+
+    /// Set `aria-labelledby` from a list of `id`s.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-labelledby).
+    #[cfg(feature = "attr-aria")]
+    fn set_aria_labelledby(&mut self, ids: &[&str]) {
+        self.set_attr(Attr::AriaLabelledBy, &ids.join(" "))
+    }
+
+    /// Get the `id`s listed in `aria-describedby`.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-describedby).
+    #[cfg(feature = "attr-aria")]
+    fn aria_describedby(&self) -> Option<Vec<&str>> {
+        Some(self.attr(&Attr::AriaDescribedBy)?.split_ascii_whitespace().collect())
+    }
+
+    /// Set `aria-describedby` from a list of `id`s.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-describedby).
+    #[cfg(feature = "attr-aria")]
+    fn set_aria_describedby(&mut self, ids: &[&str]) {
+        self.set_attr(Attr::AriaDescribedBy, &ids.join(" "))
+    }
 }
 
 macro_rules! impl_attr_accessors {
@@ -273,14 +723,8 @@ macro_rules! impl_attr_accessors {
             }
 
             fn set_attr(&mut self, attr: crate::attr::Attr, value: &str) {
-                if self.attr.is_none() {
-                    self.attr = Some(std::collections::HashMap::new());
-                }
-
-                self.attr.as_mut().unwrap()
-                    .entry(attr)
-                    .and_modify(|cur| *cur = value.to_string())
-                    .or_insert(value.to_string());
+                self.attr.get_or_insert_with(crate::attr::AttrMap::new)
+                    .insert(attr, value.to_string());
             }
 
             fn pop_attr(&mut self, attr: &crate::attr::Attr) -> Option<String> {
@@ -0,0 +1,557 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{error::Error, parser::scanner::Scanner};
+
+use super::def_keyword;
+
+/// Consume a clock value, e.g. `2.5s`, `300ms`, `1min`, `02:30` or `01:02:03.5`,
+/// and return it in seconds.
+///
+/// See [the specification](https://www.w3.org/TR/SMIL3/smil-timing.html#Timing-ClockValueSyntax).
+fn clock(scanner: &mut Scanner) -> Result<f32, Error> {
+    if matches!(scanner.peek(), Some(b'-' | b'+')) {
+        return Err(scanner.error());
+    }
+
+    let first = scanner.number()?;
+
+    if scanner.peek() == Some(b':') {
+        let mut parts = vec![first];
+
+        while scanner.peek() == Some(b':') {
+            scanner.bump();
+            parts.push(scanner.number()?);
+        }
+
+        return match parts[..] {
+            [minutes, seconds] => Ok(minutes * 60.0 + seconds),
+            [hours, minutes, seconds] => Ok(hours * 3600.0 + minutes * 60.0 + seconds),
+            _ => Err(scanner.error()),
+        };
+    }
+
+    let pos = scanner.pos();
+
+    match scanner.ident() {
+        "" | "s" => Ok(first),
+        "ms" => Ok(first / 1000.0),
+        "min" => Ok(first * 60.0),
+        "h" => Ok(first * 3600.0),
+        _ => Err(Error::InvalidClockValue(pos)),
+    }
+}
+
+/// Consume an optional signed clock value following a time base, e.g. `+1s`.
+fn offset(scanner: &mut Scanner) -> Result<f32, Error> {
+    scanner.skip_wsp();
+
+    let sign = match scanner.peek() {
+        Some(b'+') => 1.0,
+        Some(b'-') => -1.0,
+        None => return Ok(0.0),
+        _ => return Err(scanner.error()),
+    };
+
+    scanner.bump();
+    scanner.skip_wsp();
+
+    let value = clock(scanner)?;
+
+    scanner.skip_wsp();
+
+    match scanner.peek() {
+        Some(_) => Err(scanner.error()),
+        None => Ok(sign * value),
+    }
+}
+
+fn write_offset(f: &mut std::fmt::Formatter<'_>, offset: f32) -> std::fmt::Result {
+    if offset > 0.0 {
+        write!(f, "+{offset}s")
+    } else if offset < 0.0 {
+        write!(f, "{offset}s")
+    } else {
+        Ok(())
+    }
+}
+
+/// Value of `dur`, `repeatDur`, `min` and `max`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dur).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Duration {
+    /// Clock value in seconds
+    Clock(f32),
+    /// `media`
+    Media,
+    /// `indefinite`
+    Indefinite,
+}
+
+impl From<f32> for Duration {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Self::Clock(value)
+    }
+}
+
+impl From<std::time::Duration> for Duration {
+    #[inline]
+    fn from(value: std::time::Duration) -> Self {
+        Self::Clock(value.as_secs_f32())
+    }
+}
+
+impl FromStr for Duration {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "media" => Ok(Self::Media),
+            "indefinite" => Ok(Self::Indefinite),
+            value => {
+                let mut scanner = Scanner::new(value, Error::InvalidClockValue);
+                let seconds = clock(&mut scanner)?;
+
+                match scanner.peek() {
+                    Some(_) => Err(scanner.error()),
+                    None => Ok(Self::Clock(seconds)),
+                }
+            }
+        }
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Clock(seconds) => write!(f, "{seconds}s"),
+            Self::Media => f.write_str("media"),
+            Self::Indefinite => f.write_str("indefinite"),
+        }
+    }
+}
+
+def_keyword! {
+    /// Point of another animation a [`Timing`] is synchronized to
+    SyncPoint {
+        Begin => "begin",
+        End => "end",
+    }
+}
+
+/// Item of `begin` and `end`, separated by semicolons
+///
+/// Offsets are in seconds.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/begin).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Timing {
+    /// Offset from the start of the document, e.g. `2s`
+    Offset(f32),
+    /// Begin or end of another animation, e.g. `fade.end+1s`
+    Syncbase {
+        id: String,
+        point: SyncPoint,
+        offset: f32,
+    },
+    /// Event, e.g. `click` or `button.click+0.5s`
+    Event {
+        id: Option<String>,
+        event: String,
+        offset: f32,
+    },
+    /// Repetition of an animation, e.g. `spin.repeat(2)`
+    Repeat {
+        id: Option<String>,
+        iteration: u32,
+        offset: f32,
+    },
+    /// Key press, e.g. `accessKey(s)`
+    AccessKey {
+        key: char,
+        offset: f32,
+    },
+    /// Real-world time, e.g. `wallclock(2024-01-01T00:00:00Z)`
+    Wallclock(String),
+    /// `indefinite`
+    Indefinite,
+}
+
+impl Timing {
+    /// Synchronize to the begin of the animation with the given `id`.
+    pub fn with(id: &str, offset: f32) -> Self {
+        Self::Syncbase {
+            id: id.to_string(),
+            point: SyncPoint::Begin,
+            offset,
+        }
+    }
+
+    /// Synchronize to the end of the animation with the given `id`.
+    pub fn after(id: &str, offset: f32) -> Self {
+        Self::Syncbase {
+            id: id.to_string(),
+            point: SyncPoint::End,
+            offset,
+        }
+    }
+
+    /// Start on an event, e.g. `click`, of the element with the given `id`,
+    /// or of the animated element without `id`.
+    pub fn on(id: Option<&str>, event: &str) -> Self {
+        Self::Event {
+            id: id.map(str::to_string),
+            event: event.to_string(),
+            offset: 0.0,
+        }
+    }
+}
+
+impl From<f32> for Timing {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Self::Offset(value)
+    }
+}
+
+impl FromStr for Timing {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s == "indefinite" {
+            return Ok(Self::Indefinite);
+        }
+
+        if let Some(time) = s.strip_prefix("wallclock(").and_then(|rest| rest.strip_suffix(')')) {
+            return Ok(Self::Wallclock(time.trim().to_string()));
+        }
+
+        if let Some(rest) = s.strip_prefix("accessKey(") {
+            let mut chars = rest.chars();
+            let key = chars.next().ok_or(Error::InvalidClockValue(s.len()))?;
+            let rest = chars.as_str().strip_prefix(')').ok_or(Error::InvalidClockValue(s.len() - chars.as_str().len()))?;
+
+            return Ok(Self::AccessKey { key, offset: offset(&mut Scanner::new(rest, Error::InvalidClockValue))? });
+        }
+
+        if matches!(s.as_bytes().first(), Some(b'0'..=b'9' | b'.' | b'+' | b'-')) {
+            let mut scanner = Scanner::new(s, Error::InvalidClockValue);
+
+            return match scanner.peek() {
+                Some(b'+' | b'-') => Ok(Self::Offset(offset(&mut scanner)?)),
+                _ => {
+                    let value = clock(&mut scanner)?;
+                    scanner.skip_wsp();
+
+                    match scanner.peek() {
+                        Some(_) => Err(scanner.error()),
+                        None => Ok(Self::Offset(value)),
+                    }
+                }
+            };
+        }
+
+        // The offset starts at the first sign followed by a valid clock value,
+        // since ids may contain hyphens
+        let (base, offset) = s.match_indices(['+', '-'])
+            .find_map(|(i, _)| Some((&s[..i], offset(&mut Scanner::new(&s[i..], Error::InvalidClockValue)).ok()?)))
+            .unwrap_or((s, 0.0));
+
+        let base = base.trim_end();
+
+        let (id, event) = match base.rsplit_once('.') {
+            Some((id, event)) => (Some(id.to_string()), event),
+            None => (None, base),
+        };
+
+        if event.is_empty() || id.as_ref().is_some_and(String::is_empty) {
+            return Err(Error::InvalidClockValue(base.len()));
+        }
+
+        if let Some(iteration) = event.strip_prefix("repeat(").and_then(|rest| rest.strip_suffix(')')) {
+            return Ok(Self::Repeat { id, iteration: iteration.trim().parse()?, offset });
+        }
+
+        Ok(match (id, event) {
+            (Some(id), "begin") => Self::Syncbase { id, point: SyncPoint::Begin, offset },
+            (Some(id), "end") => Self::Syncbase { id, point: SyncPoint::End, offset },
+            (id, event) => Self::Event { id, event: event.to_string(), offset },
+        })
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let offset = match self {
+            Self::Offset(offset) => return write!(f, "{offset}s"),
+            Self::Syncbase { id, point, offset } => {
+                write!(f, "{id}.{point}")?;
+                offset
+            }
+            Self::Event { id, event, offset } => {
+                if let Some(id) = id {
+                    write!(f, "{id}.")?;
+                }
+
+                f.write_str(event)?;
+                offset
+            }
+            Self::Repeat { id, iteration, offset } => {
+                if let Some(id) = id {
+                    write!(f, "{id}.")?;
+                }
+
+                write!(f, "repeat({iteration})")?;
+                offset
+            }
+            Self::AccessKey { key, offset } => {
+                write!(f, "accessKey({key})")?;
+                offset
+            }
+            Self::Wallclock(time) => return write!(f, "wallclock({time})"),
+            Self::Indefinite => return f.write_str("indefinite"),
+        };
+
+        write_offset(f, *offset)
+    }
+}
+
+/// Value of `repeatCount`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/repeatCount).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RepeatCount {
+    /// Number of iterations, which may be fractional
+    Count(f32),
+    /// `indefinite`
+    Indefinite,
+}
+
+impl From<f32> for RepeatCount {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Self::Count(value)
+    }
+}
+
+impl FromStr for RepeatCount {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "indefinite" => Ok(Self::Indefinite),
+            count => Ok(Self::Count(count.parse()?)),
+        }
+    }
+}
+
+impl Display for RepeatCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Count(count) => write!(f, "{count}"),
+            Self::Indefinite => f.write_str("indefinite"),
+        }
+    }
+}
+
+/// Item of `keySplines`, i.e. the control points of a cubic Bézier curve
+/// from `(0, 0)` to `(1, 1)`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/keySplines).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeySpline {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+}
+
+impl KeySpline {
+    /// Same as CSS `ease`.
+    pub const EASE: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+    /// Same as CSS `ease-in`.
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+    /// Same as CSS `ease-out`.
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+    /// Same as CSS `ease-in-out`.
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    #[inline]
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+}
+
+impl FromStr for KeySpline {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s.split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|item| !item.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<f32>, _>>()?;
+
+        match values[..] {
+            [x1, y1, x2, y2] => Ok(Self::new(x1, y1, x2, y2)),
+            _ => Err(Error::NoOptionFound),
+        }
+    }
+}
+
+impl Display for KeySpline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {} {}", self.x1, self.y1, self.x2, self.y2)
+    }
+}
+
+/// Value of `rotate` of `<animateMotion>`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/rotate).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MotionRotate {
+    /// Follow the direction of the path
+    Auto,
+    /// Follow the opposite direction of the path
+    AutoReverse,
+    /// Fixed angle in degrees
+    Angle(f32),
+}
+
+impl FromStr for MotionRotate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "auto" => Ok(Self::Auto),
+            "auto-reverse" => Ok(Self::AutoReverse),
+            angle => Ok(Self::Angle(angle.parse()?)),
+        }
+    }
+}
+
+impl Display for MotionRotate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => f.write_str("auto"),
+            Self::AutoReverse => f.write_str("auto-reverse"),
+            Self::Angle(angle) => write!(f, "{angle}"),
+        }
+    }
+}
+
+def_keyword! {
+    /// `fill` attribute of animation elements
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill#animate).
+    #[derive(Default)]
+    AnimationFill {
+        /// Keep the last value after the animation ends
+        Freeze => "freeze",
+        /// Remove the effect after the animation ends
+        #[default]
+        Remove => "remove",
+    }
+}
+
+def_keyword! {
+    /// `restart` attribute
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/restart).
+    #[derive(Default)]
+    Restart {
+        #[default]
+        Always => "always",
+        WhenNotActive => "whenNotActive",
+        Never => "never",
+    }
+}
+
+def_keyword! {
+    /// `calcMode` attribute
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/calcMode).
+    CalcMode {
+        /// Jump from one value to the next
+        Discrete => "discrete",
+        /// Interpolate linearly
+        Linear => "linear",
+        /// Interpolate at an even pace, ignoring `keyTimes`
+        Paced => "paced",
+        /// Interpolate along `keySplines`
+        Spline => "spline",
+    }
+}
+
+def_keyword! {
+    /// `additive` attribute
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/additive).
+    #[derive(Default)]
+    Additive {
+        #[default]
+        Replace => "replace",
+        Sum => "sum",
+    }
+}
+
+def_keyword! {
+    /// `accumulate` attribute
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/accumulate).
+    #[derive(Default)]
+    Accumulate {
+        #[default]
+        None => "none",
+        Sum => "sum",
+    }
+}
+
+def_keyword! {
+    /// `type` attribute of `<animateTransform>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/type#animatetransform).
+    #[derive(Default)]
+    TransformType {
+        #[default]
+        Translate => "translate",
+        Scale => "scale",
+        Rotate => "rotate",
+        SkewX => "skewX",
+        SkewY => "skewY",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_timing() {
+        assert_eq!("1:30".parse::<Duration>().unwrap(), Duration::Clock(90.0));
+        assert_eq!("00:01:00.5".parse::<Duration>().unwrap(), Duration::Clock(60.5));
+        assert_eq!(" 250ms".parse::<Duration>().unwrap(), Duration::Clock(0.25));
+        assert_eq!("2min".parse::<Duration>().unwrap().to_string(), "120s");
+        assert!(matches!("3days".parse::<Duration>(), Err(Error::InvalidClockValue(1))));
+
+        let cases = [
+            ("-0.5s", Timing::Offset(-0.5)),
+            ("fade-in.end+1s", Timing::after("fade-in", 1.0)),
+            ("intro.begin", Timing::with("intro", 0.0)),
+            ("click", Timing::on(None, "click")),
+            ("spin.repeat(2)", Timing::Repeat { id: Some("spin".to_string()), iteration: 2, offset: 0.0 }),
+            ("accessKey(s)-1.5s", Timing::AccessKey { key: 's', offset: -1.5 }),
+            ("indefinite", Timing::Indefinite),
+        ];
+
+        for (s, timing) in cases {
+            assert_eq!(s.parse::<Timing>().unwrap(), timing);
+            assert_eq!(timing.to_string(), s);
+        }
+
+        assert_eq!("button.click + 200ms".parse::<Timing>().unwrap().to_string(), "button.click+0.2s");
+        assert!(".end".parse::<Timing>().is_err());
+    }
+}
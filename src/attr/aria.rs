@@ -0,0 +1,33 @@
+use super::def_keyword;
+
+def_keyword! {
+    /// Value of `role`, limited to the roles relevant to graphics
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles).
+    Role {
+        /// Single image, labelled as a whole
+        Img => "img",
+        /// Document made of graphics, e.g. a chart
+        GraphicsDocument => "graphics-document",
+        /// Meaningful part of a graphics document, e.g. a data series
+        GraphicsObject => "graphics-object",
+        /// Symbol conveying a meaning, e.g. a legend marker
+        GraphicsSymbol => "graphics-symbol",
+        Group => "group",
+        Figure => "figure",
+        List => "list",
+        ListItem => "listitem",
+        Table => "table",
+        Row => "row",
+        Cell => "cell",
+        Heading => "heading",
+        Link => "link",
+        Button => "button",
+        Note => "note",
+        Region => "region",
+        /// Hidden from the accessibility tree, along with its children
+        Presentation => "presentation",
+        /// Same as `presentation`
+        None => "none",
+    }
+}
@@ -0,0 +1,440 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{error::Error, parser::scanner::Scanner};
+
+mod named;
+
+use self::named::NAMED_COLORS;
+
+/// sRGB color with an alpha channel
+///
+/// `alpha` ranges from `0.0` (transparent) to `1.0` (opaque).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgba {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: f32,
+}
+
+impl Rgba {
+    /// Create an opaque color.
+    #[inline]
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue, alpha: 1.0 }
+    }
+
+    /// Create a color with alpha.
+    #[inline]
+    pub fn new(red: u8, green: u8, blue: u8, alpha: f32) -> Self {
+        Self { red, green, blue, alpha: alpha.clamp(0.0, 1.0) }
+    }
+
+    /// Create a color from hue (in degrees), saturation and lightness
+    /// (from `0.0` to `1.0`), and alpha.
+    pub fn hsla(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let s = saturation.clamp(0.0, 1.0);
+        let l = lightness.clamp(0.0, 1.0);
+
+        // See https://www.w3.org/TR/css-color-4/#hsl-to-rgb
+        let channel = |n: f32| {
+            let k = (n + hue.rem_euclid(360.0) / 30.0) % 12.0;
+            let a = s * l.min(1.0 - l);
+
+            to_channel(l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0))
+        };
+
+        Self::new(channel(0.0), channel(8.0), channel(4.0), alpha)
+    }
+
+    /// Look up a color keyword, case-insensitively.
+    pub fn named(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+
+        NAMED_COLORS.binary_search_by(|(key, _)| key.cmp(&name.as_str()))
+            .ok()
+            .map(|i| {
+                let [r, g, b] = NAMED_COLORS[i].1;
+                Self::rgb(r, g, b)
+            })
+    }
+
+    /// Shortest color keyword for the color, if any.
+    ///
+    /// Only opaque colors have keywords.
+    pub fn name(&self) -> Option<&'static str> {
+        if self.alpha < 1.0 {
+            return None;
+        }
+
+        NAMED_COLORS.iter()
+            .filter(|(_, rgb)| *rgb == [self.red, self.green, self.blue])
+            .map(|(name, _)| *name)
+            .min_by_key(|name| name.len())
+    }
+
+    /// Convert to hue (in degrees), saturation and lightness (from `0.0` to `1.0`).
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let r = self.red as f32 / 255.0;
+        let g = self.green as f32 / 255.0;
+        let b = self.blue as f32 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let lightness = (max + min) / 2.0;
+
+        if delta == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+
+        let hue = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        (hue, saturation, lightness)
+    }
+
+    /// Composite the color over a background (source-over).
+    pub fn blend_over(&self, background: &Rgba) -> Rgba {
+        let alpha = self.alpha + background.alpha * (1.0 - self.alpha);
+
+        if alpha == 0.0 {
+            return Rgba::new(0, 0, 0, 0.0);
+        }
+
+        let channel = |fg: u8, bg: u8| {
+            let value = (fg as f32 * self.alpha + bg as f32 * background.alpha * (1.0 - self.alpha)) / alpha;
+            value.round().clamp(0.0, 255.0) as u8
+        };
+
+        Rgba::new(
+            channel(self.red, background.red),
+            channel(self.green, background.green),
+            channel(self.blue, background.blue),
+            alpha,
+        )
+    }
+
+    fn hex(&self) -> String {
+        let (r, g, b) = (self.red, self.green, self.blue);
+
+        // Use `#rgb` when every channel is made of a repeated digit
+        if r % 17 == 0 && g % 17 == 0 && b % 17 == 0 {
+            format!("#{:x}{:x}{:x}", r / 17, g / 17, b / 17)
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        }
+    }
+}
+
+impl Display for Rgba {
+    /// Write the color in its shortest form.
+    ///
+    /// Opaque colors become a keyword or a hex code, while translucent colors
+    /// use `rgba()` to stay readable by SVG 1.1 tooling.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.alpha >= 1.0 {
+            let hex = self.hex();
+
+            match self.name() {
+                Some(name) if name.len() < hex.len() => f.write_str(name),
+                _ => f.write_str(&hex),
+            }
+        } else if *self == Rgba::new(0, 0, 0, 0.0) {
+            f.write_str("transparent")
+        } else {
+            write!(f, "rgba({},{},{},{})", self.red, self.green, self.blue, self.alpha)
+        }
+    }
+}
+
+/// Color value
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Color {
+    /// `currentColor`, i.e. the value of the `color` property
+    CurrentColor,
+    /// Concrete color
+    Rgba(Rgba),
+}
+
+impl Color {
+    /// Create an opaque color.
+    #[inline]
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::Rgba(Rgba::rgb(red, green, blue))
+    }
+
+    /// Create a color with alpha.
+    #[inline]
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f32) -> Self {
+        Self::Rgba(Rgba::new(red, green, blue, alpha))
+    }
+
+    /// Create a color from HSL and alpha.
+    ///
+    /// See [`Rgba::hsla`].
+    #[inline]
+    pub fn hsla(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        Self::Rgba(Rgba::hsla(hue, saturation, lightness, alpha))
+    }
+
+    /// Look up a color keyword, case-insensitively.
+    #[inline]
+    pub fn named(name: &str) -> Option<Self> {
+        Rgba::named(name).map(Self::Rgba)
+    }
+
+    /// Access the concrete color, or `None` for `currentColor`.
+    #[inline]
+    pub fn rgba_value(&self) -> Option<&Rgba> {
+        match self {
+            Self::Rgba(rgba) => Some(rgba),
+            Self::CurrentColor => None,
+        }
+    }
+}
+
+impl From<Rgba> for Color {
+    #[inline]
+    fn from(value: Rgba) -> Self {
+        Self::Rgba(value)
+    }
+}
+
+#[inline]
+fn to_channel(fraction: f32) -> u8 {
+    (fraction * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+fn parse_hex(hex: &str) -> Option<Rgba> {
+    if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).ok().map(|d| d * 17);
+    let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+
+    match hex.len() {
+        3 => Some(Rgba::rgb(digit(0)?, digit(1)?, digit(2)?)),
+        4 => Some(Rgba::new(digit(0)?, digit(1)?, digit(2)?, digit(3)? as f32 / 255.0)),
+        6 => Some(Rgba::rgb(pair(0)?, pair(2)?, pair(4)?)),
+        8 => Some(Rgba::new(pair(0)?, pair(2)?, pair(4)?, pair(6)? as f32 / 255.0)),
+        _ => None,
+    }
+}
+
+/// Read a number with an optional `%` sign.
+fn number_or_percentage(scanner: &mut Scanner) -> Result<(f32, bool), Error> {
+    let value = scanner.number()?;
+
+    if scanner.peek() == Some(b'%') {
+        scanner.bump();
+        Ok((value, true))
+    } else {
+        Ok((value, false))
+    }
+}
+
+/// Read the optional alpha of a color function, then the closing parenthesis.
+fn alpha_and_end(scanner: &mut Scanner) -> Result<f32, Error> {
+    scanner.skip_wsp();
+
+    let alpha = if scanner.peek() == Some(b')') {
+        1.0
+    } else {
+        // Either `, alpha` or `/ alpha`
+        if scanner.peek() == Some(b'/') {
+            scanner.bump();
+            scanner.skip_wsp();
+        } else {
+            scanner.skip_comma_wsp();
+        }
+
+        match number_or_percentage(scanner)? {
+            (value, true) => value / 100.0,
+            (value, false) => value,
+        }
+    };
+
+    scanner.skip_wsp();
+    scanner.expect(b')')?;
+
+    if scanner.peek().is_some() {
+        return Err(scanner.error());
+    }
+
+    Ok(alpha)
+}
+
+/// Parse `rgb()`/`rgba()` arguments, in both legacy and space-separated syntaxes.
+fn parse_rgb(scanner: &mut Scanner) -> Result<Rgba, Error> {
+    let mut channels = [0; 3];
+
+    for (i, channel) in channels.iter_mut().enumerate() {
+        if i > 0 {
+            scanner.skip_comma_wsp();
+        }
+
+        *channel = match number_or_percentage(scanner)? {
+            (value, true) => to_channel(value / 100.0),
+            (value, false) => value.round().clamp(0.0, 255.0) as u8,
+        };
+    }
+
+    let alpha = alpha_and_end(scanner)?;
+
+    Ok(Rgba::new(channels[0], channels[1], channels[2], alpha))
+}
+
+/// Parse `hsl()`/`hsla()` arguments, in both legacy and space-separated syntaxes.
+fn parse_hsl(scanner: &mut Scanner) -> Result<Rgba, Error> {
+    let value = scanner.number()?;
+
+    let unit_pos = scanner.pos();
+    let hue = match scanner.ident().to_ascii_lowercase().as_str() {
+        "" | "deg" => value,
+        "rad" => value.to_degrees(),
+        "grad" => value * 0.9,
+        "turn" => value * 360.0,
+        _ => return Err(Error::InvalidColor(unit_pos)),
+    };
+
+    scanner.skip_comma_wsp();
+    let (saturation, _) = number_or_percentage(scanner)?;
+
+    scanner.skip_comma_wsp();
+    let (lightness, _) = number_or_percentage(scanner)?;
+
+    let alpha = alpha_and_end(scanner)?;
+
+    Ok(Rgba::hsla(hue, saturation / 100.0, lightness / 100.0, alpha))
+}
+
+impl FromStr for Rgba {
+    type Err = Error;
+
+    /// Parse a CSS color, except `currentColor`.
+    ///
+    /// On malformed input, the error carries the byte offset
+    /// where parsing has failed.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex).ok_or(Error::InvalidColor(0));
+        }
+
+        if let Some(rgba) = Rgba::named(s) {
+            return Ok(rgba);
+        }
+
+        if s.eq_ignore_ascii_case("transparent") {
+            return Ok(Rgba::new(0, 0, 0, 0.0));
+        }
+
+        let mut scanner = Scanner::new(s, Error::InvalidColor);
+        let name = scanner.ident().to_ascii_lowercase();
+
+        if scanner.peek() != Some(b'(') {
+            return Err(Error::InvalidColor(0));
+        }
+
+        scanner.bump();
+        scanner.skip_wsp();
+
+        match name.as_str() {
+            "rgb" | "rgba" => parse_rgb(&mut scanner),
+            "hsl" | "hsla" => parse_hsl(&mut scanner),
+            _ => Err(Error::InvalidColor(0)),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = Error;
+
+    /// Parse a CSS color.
+    ///
+    /// See [`Rgba::from_str`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().eq_ignore_ascii_case("currentColor") {
+            Ok(Self::CurrentColor)
+        } else {
+            s.parse().map(Self::Rgba)
+        }
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CurrentColor => f.write_str("currentColor"),
+            Self::Rgba(rgba) => write!(f, "{rgba}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn named_colors() {
+        assert_eq!(NAMED_COLORS.len(), 147);
+        assert!(NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        assert_eq!(Rgba::named("RebeccaPurple"), None);
+        assert_eq!(Rgba::named("DarkSlateGrey"), Some(Rgba::rgb(47, 79, 79)));
+        assert_eq!(Rgba::rgb(0, 255, 255).name(), Some("aqua"));
+    }
+
+    #[test]
+    fn parse_syntaxes() {
+        let orange = Color::rgb(255, 165, 0);
+
+        for s in [
+            "orange", "ORANGE", "#ffa500", "#FFA500ff", "rgb(255, 165, 0)", "rgb(100%,64.7%,0%)",
+            "rgba(255 165 0 / 100%)", "hsl(38.8, 100%, 50%)", "hsla(0.1078turn 100% 50% / 1)",
+        ] {
+            assert_eq!(s.parse::<Color>().unwrap(), orange, "{s}");
+        }
+
+        assert_eq!("#0f08".parse::<Color>().unwrap(), Color::rgba(0, 255, 0, 136.0 / 255.0));
+        assert_eq!("currentcolor".parse::<Color>().unwrap(), Color::CurrentColor);
+
+        assert!(matches!("#ffff0".parse::<Color>(), Err(Error::InvalidColor(0))));
+        assert!(matches!("rgb(1, 2)".parse::<Color>(), Err(Error::InvalidColor(8))));
+        assert!(matches!("hsl(1foo, 0%, 0%)".parse::<Color>(), Err(Error::InvalidColor(5))));
+    }
+
+    #[test]
+    fn shortest_form() {
+        assert_eq!(Color::rgb(255, 0, 0).to_string(), "red");
+        assert_eq!(Color::rgb(255, 255, 255).to_string(), "#fff");
+        assert_eq!(Color::rgb(0, 0, 128).to_string(), "navy");
+        assert_eq!(Color::rgb(18, 52, 86).to_string(), "#123456");
+        assert_eq!(Color::rgba(0, 0, 0, 0.0).to_string(), "transparent");
+        assert_eq!(Color::rgba(255, 0, 0, 0.5).to_string(), "rgba(255,0,0,0.5)");
+    }
+
+    #[test]
+    fn convert_and_blend() {
+        let (h, s, l) = Rgba::rgb(255, 165, 0).to_hsl();
+        assert!((h - 38.82).abs() < 0.01 && s == 1.0 && l == 0.5);
+
+        assert_eq!(Rgba::hsla(240.0, 1.0, 0.25, 1.0), Rgba::rgb(0, 0, 128));
+
+        let blended = Rgba::new(255, 0, 0, 0.5).blend_over(&Rgba::rgb(0, 0, 255));
+        assert_eq!(blended, Rgba::rgb(128, 0, 128));
+    }
+}
@@ -0,0 +1,45 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::error::Error;
+
+use super::AsValue;
+
+/// Value of `crossorigin`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/crossorigin).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CrossOrigin {
+    /// Request without credentials unless same-origin
+    #[default]
+    Anonymous,
+    /// Request with credentials
+    UseCredentials,
+}
+
+impl FromStr for CrossOrigin {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            // An empty value is the same as `anonymous`
+            "" | "anonymous" => Ok(Self::Anonymous),
+            "use-credentials" => Ok(Self::UseCredentials),
+            _ => Err(Error::NoOptionFound),
+        }
+    }
+}
+
+impl AsValue for CrossOrigin {
+    fn as_value(&self) -> &str {
+        match self {
+            Self::Anonymous => "anonymous",
+            Self::UseCredentials => "use-credentials",
+        }
+    }
+}
+
+impl Display for CrossOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_value())
+    }
+}
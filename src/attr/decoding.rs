@@ -0,0 +1,17 @@
+use super::def_keyword;
+
+def_keyword! {
+    /// Value of `decoding` of `<image>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/decoding).
+    #[derive(Default)]
+    Decoding {
+        /// Decode along with the rest of the content
+        Sync => "sync",
+        /// Decode without delaying the rest of the content
+        Async => "async",
+        /// Let the user agent decide
+        #[default]
+        Auto => "auto",
+    }
+}
@@ -0,0 +1,300 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{error::Error, serialize::{FormatValue, SerializeOptions}};
+
+use super::def_keyword;
+
+/// Name of the output of a filter primitive, set as its `result`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/result).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FilterResult(String);
+
+impl FilterResult {
+    #[inline]
+    pub fn new(name: &str) -> Self {
+        Self(name.to_string())
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for FilterResult {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s.trim()))
+    }
+}
+
+impl Display for FilterResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Input of a filter primitive, i.e. `in` or `in2`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/in).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilterInput {
+    /// `SourceGraphic`
+    SourceGraphic,
+    /// `SourceAlpha`
+    SourceAlpha,
+    /// `BackgroundImage`
+    BackgroundImage,
+    /// `BackgroundAlpha`
+    BackgroundAlpha,
+    /// `FillPaint`
+    FillPaint,
+    /// `StrokePaint`
+    StrokePaint,
+    /// Output of a previous primitive
+    Result(FilterResult),
+}
+
+impl From<FilterResult> for FilterInput {
+    #[inline]
+    fn from(value: FilterResult) -> Self {
+        Self::Result(value)
+    }
+}
+
+impl From<&FilterResult> for FilterInput {
+    #[inline]
+    fn from(value: &FilterResult) -> Self {
+        Self::Result(value.clone())
+    }
+}
+
+impl FromStr for FilterInput {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "SourceGraphic" => Self::SourceGraphic,
+            "SourceAlpha" => Self::SourceAlpha,
+            "BackgroundImage" => Self::BackgroundImage,
+            "BackgroundAlpha" => Self::BackgroundAlpha,
+            "FillPaint" => Self::FillPaint,
+            "StrokePaint" => Self::StrokePaint,
+            name => Self::Result(FilterResult::new(name)),
+        })
+    }
+}
+
+impl Display for FilterInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SourceGraphic => f.write_str("SourceGraphic"),
+            Self::SourceAlpha => f.write_str("SourceAlpha"),
+            Self::BackgroundImage => f.write_str("BackgroundImage"),
+            Self::BackgroundAlpha => f.write_str("BackgroundAlpha"),
+            Self::FillPaint => f.write_str("FillPaint"),
+            Self::StrokePaint => f.write_str("StrokePaint"),
+            Self::Result(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+/// Number with an optional second number, e.g. `stdDeviation="2 4"`
+///
+/// The second number defaults to the first one.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NumberOptionalNumber(pub f32, pub Option<f32>);
+
+impl NumberOptionalNumber {
+    /// Access the first number.
+    #[inline]
+    pub fn x(&self) -> f32 {
+        self.0
+    }
+
+    /// Access the second number, defaulting to the first one.
+    #[inline]
+    pub fn y(&self) -> f32 {
+        self.1.unwrap_or(self.0)
+    }
+}
+
+impl From<f32> for NumberOptionalNumber {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Self(value, None)
+    }
+}
+
+impl From<(f32, f32)> for NumberOptionalNumber {
+    #[inline]
+    fn from((x, y): (f32, f32)) -> Self {
+        Self(x, Some(y))
+    }
+}
+
+impl FromStr for NumberOptionalNumber {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = s.split(|c: char| c.is_whitespace() || c == ',').filter(|item| !item.is_empty());
+
+        let x = numbers.next().ok_or(Error::NoOptionFound)?.parse()?;
+        let y = numbers.next().map(str::parse).transpose()?;
+
+        if numbers.next().is_some() {
+            return Err(Error::NoOptionFound);
+        }
+
+        Ok(Self(x, y))
+    }
+}
+
+impl Display for NumberOptionalNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.1 {
+            Some(y) => write!(f, "{} {}", self.0, y),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl FormatValue for NumberOptionalNumber {
+    fn format_value(&self, options: &SerializeOptions) -> String {
+        match self.1 {
+            Some(y) => format!("{} {}", options.numbers.format(self.0), options.numbers.format(y)),
+            None => options.numbers.format(self.0),
+        }
+    }
+}
+
+def_keyword! {
+    /// `mode` attribute of `<feBlend>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mode).
+    #[derive(Default)]
+    BlendMode {
+        #[default]
+        Normal => "normal",
+        Multiply => "multiply",
+        Screen => "screen",
+        Overlay => "overlay",
+        Darken => "darken",
+        Lighten => "lighten",
+        ColorDodge => "color-dodge",
+        ColorBurn => "color-burn",
+        HardLight => "hard-light",
+        SoftLight => "soft-light",
+        Difference => "difference",
+        Exclusion => "exclusion",
+        Hue => "hue",
+        Saturation => "saturation",
+        Color => "color",
+        Luminosity => "luminosity",
+    }
+}
+
+def_keyword! {
+    /// `type` attribute of `<feColorMatrix>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/type#fecolormatrix).
+    #[derive(Default)]
+    ColorMatrixType {
+        /// Full 5x4 matrix in `values`
+        #[default]
+        Matrix => "matrix",
+        /// Saturation from `0` to `1` in `values`
+        Saturate => "saturate",
+        /// Hue rotation in degrees in `values`
+        HueRotate => "hueRotate",
+        /// Luminance converted to alpha
+        LuminanceToAlpha => "luminanceToAlpha",
+    }
+}
+
+def_keyword! {
+    /// `operator` attribute of `<feComposite>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/operator#fecomposite).
+    #[derive(Default)]
+    CompositeOperator {
+        #[default]
+        Over => "over",
+        In => "in",
+        Out => "out",
+        Atop => "atop",
+        Xor => "xor",
+        Lighter => "lighter",
+        /// Combination weighted by `k1` to `k4`
+        Arithmetic => "arithmetic",
+    }
+}
+
+def_keyword! {
+    /// `operator` attribute of `<feMorphology>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/operator#femorphology).
+    #[derive(Default)]
+    MorphologyOperator {
+        /// Thin the input
+        #[default]
+        Erode => "erode",
+        /// Fatten the input
+        Dilate => "dilate",
+    }
+}
+
+def_keyword! {
+    /// `type` attribute of `<feTurbulence>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/type#feturbulence).
+    #[derive(Default)]
+    TurbulenceType {
+        FractalNoise => "fractalNoise",
+        #[default]
+        Turbulence => "turbulence",
+    }
+}
+
+def_keyword! {
+    /// `stitchTiles` attribute of `<feTurbulence>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stitchTiles).
+    #[derive(Default)]
+    StitchTiles {
+        Stitch => "stitch",
+        #[default]
+        NoStitch => "noStitch",
+    }
+}
+
+def_keyword! {
+    /// `xChannelSelector` and `yChannelSelector` attributes of `<feDisplacementMap>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xChannelSelector).
+    #[derive(Default)]
+    ChannelSelector {
+        R => "R",
+        G => "G",
+        B => "B",
+        #[default]
+        A => "A",
+    }
+}
+
+def_keyword! {
+    /// `edgeMode` attribute
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/edgeMode).
+    EdgeMode {
+        /// Extend the edges of the input
+        Duplicate => "duplicate",
+        /// Take pixels from the opposite edge
+        Wrap => "wrap",
+        /// Extend the input with transparent black
+        None => "none",
+    }
+}
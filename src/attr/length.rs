@@ -0,0 +1,269 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{error::Error, parser::scanner::Scanner};
+
+use super::AsValue;
+
+/// Length unit enumeration
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Content_type#length).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LengthUnit {
+    /// No unit, i.e. user units
+    #[default]
+    None,
+    /// `px`
+    Px,
+    /// `em`
+    Em,
+    /// `ex`
+    Ex,
+    /// `%`
+    Percent,
+    /// `pt`
+    Pt,
+    /// `pc`
+    Pc,
+    /// `mm`
+    Mm,
+    /// `cm`
+    Cm,
+    /// `in`
+    In,
+}
+
+impl LengthUnit {
+    /// User units per unit, or `None` for relative units.
+    pub fn user_units(&self) -> Option<f32> {
+        match self {
+            Self::None | Self::Px => Some(1.0),
+            Self::Pt => Some(96.0 / 72.0),
+            Self::Pc => Some(16.0),
+            Self::Mm => Some(96.0 / 25.4),
+            Self::Cm => Some(96.0 / 2.54),
+            Self::In => Some(96.0),
+            Self::Em | Self::Ex | Self::Percent => None,
+        }
+    }
+}
+
+impl FromStr for LengthUnit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Ok(Self::None),
+            "px" => Ok(Self::Px),
+            "em" => Ok(Self::Em),
+            "ex" => Ok(Self::Ex),
+            "%" => Ok(Self::Percent),
+            "pt" => Ok(Self::Pt),
+            "pc" => Ok(Self::Pc),
+            "mm" => Ok(Self::Mm),
+            "cm" => Ok(Self::Cm),
+            "in" => Ok(Self::In),
+            _ => Err(Error::NoOptionFound),
+        }
+    }
+}
+
+impl AsValue for LengthUnit {
+    fn as_value(&self) -> &str {
+        match self {
+            Self::None => "",
+            Self::Px => "px",
+            Self::Em => "em",
+            Self::Ex => "ex",
+            Self::Percent => "%",
+            Self::Pt => "pt",
+            Self::Pc => "pc",
+            Self::Mm => "mm",
+            Self::Cm => "cm",
+            Self::In => "in",
+        }
+    }
+}
+
+impl Display for LengthUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_value())
+    }
+}
+
+/// Axis a length is measured along, which decides what percentages refer to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthDirection {
+    /// Percentage of the viewport width
+    Horizontal,
+    /// Percentage of the viewport height
+    Vertical,
+    /// Percentage of the normalized viewport diagonal, e.g. for radii
+    Other,
+}
+
+/// Context for resolving relative lengths
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LengthContext {
+    pub viewport_width: f32,
+    pub viewport_height: f32,
+    pub font_size: f32,
+    pub x_height: f32,
+}
+
+impl LengthContext {
+    /// Create a context for a viewport, with the default `16px` font size
+    /// and an x-height of half of it.
+    pub fn new(viewport_width: f32, viewport_height: f32) -> Self {
+        Self {
+            viewport_width,
+            viewport_height,
+            font_size: 16.0,
+            x_height: 8.0,
+        }
+    }
+}
+
+/// Length value
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Content_type#length).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Length {
+    value: f32,
+    unit: LengthUnit,
+}
+
+impl Length {
+    /// Create a length with a unit.
+    #[inline]
+    pub fn new(value: f32, unit: LengthUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Create a length in user units.
+    #[inline]
+    pub fn user(value: f32) -> Self {
+        Self::new(value, LengthUnit::None)
+    }
+
+    /// Create a percentage.
+    #[inline]
+    pub fn percent(value: f32) -> Self {
+        Self::new(value, LengthUnit::Percent)
+    }
+
+    /// Access the numeric value.
+    #[inline]
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Access the unit.
+    #[inline]
+    pub fn unit(&self) -> LengthUnit {
+        self.unit
+    }
+
+    /// Convert to user units, or `None` if the unit is relative.
+    #[inline]
+    pub fn to_absolute_user_units(&self) -> Option<f32> {
+        Some(self.value * self.unit.user_units()?)
+    }
+
+    /// Convert to user units, resolving relative units by the context.
+    pub fn to_user_units(&self, ctx: &LengthContext, direction: LengthDirection) -> f32 {
+        match self.unit {
+            LengthUnit::Em => self.value * ctx.font_size,
+            LengthUnit::Ex => self.value * ctx.x_height,
+            LengthUnit::Percent => {
+                let reference = match direction {
+                    LengthDirection::Horizontal => ctx.viewport_width,
+                    LengthDirection::Vertical => ctx.viewport_height,
+                    LengthDirection::Other => ((ctx.viewport_width.powi(2) + ctx.viewport_height.powi(2)) / 2.0).sqrt(),
+                };
+
+                self.value * reference / 100.0
+            }
+            // Absolute units always convert
+            _ => self.to_absolute_user_units().unwrap_or_default(),
+        }
+    }
+}
+
+impl From<f32> for Length {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Self::user(value)
+    }
+}
+
+impl From<i32> for Length {
+    #[inline]
+    fn from(value: i32) -> Self {
+        Self::user(value as f32)
+    }
+}
+
+impl FromStr for Length {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let mut scanner = Scanner::new(s, Error::InvalidLength);
+        let value = scanner.number()?;
+
+        let unit = s[scanner.pos()..].parse()
+            .map_err(|_| Error::InvalidLength(scanner.pos()))?;
+
+        Ok(Self { value, unit })
+    }
+}
+
+impl Display for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_and_serialize() {
+        for s in ["10", "-1.5px", "2em", "3ex", "50%", "12pt", "1pc", "10mm", "1cm", "0.5in"] {
+            assert_eq!(s.parse::<Length>().unwrap().to_string(), s);
+        }
+
+        assert_eq!(" 1e2% ".parse::<Length>().unwrap(), Length::percent(100.0));
+        assert!(matches!("10 px".parse::<Length>(), Err(Error::InvalidLength(2))));
+        assert!(matches!("px".parse::<Length>(), Err(Error::InvalidLength(0))));
+    }
+
+    #[test]
+    fn keep_unparsed_lengths() {
+        use crate::{attr::{AccessAttr, Attr}, element::ElementKind};
+
+        let ElementKind::Rect(mut rect) = crate::parser::parse(r#"<rect x="1rem" y="calc(1px + 2px)" width="10" height="auto"/>"#).unwrap() else {
+            unreachable!()
+        };
+
+        assert_eq!(rect.x(), None);
+        assert_eq!(rect.width(), Some(Length::user(10.0)));
+        assert_eq!(rect.attr(&Attr::from("y")), Some("calc(1px + 2px)"));
+        assert_eq!(rect.to_string(), r#"<rect width="10" x="1rem" y="calc(1px + 2px)" height="auto"/>"#);
+
+        rect.set_x(Some(2));
+        assert_eq!(rect.to_string(), r#"<rect x="2" width="10" y="calc(1px + 2px)" height="auto"/>"#);
+    }
+
+    #[test]
+    fn resolve_user_units() {
+        let ctx = LengthContext::new(300.0, 400.0);
+
+        assert_eq!("1in".parse::<Length>().unwrap().to_user_units(&ctx, LengthDirection::Horizontal), 96.0);
+        assert_eq!("2em".parse::<Length>().unwrap().to_user_units(&ctx, LengthDirection::Horizontal), 32.0);
+        assert_eq!(Length::percent(50.0).to_user_units(&ctx, LengthDirection::Vertical), 200.0);
+        assert_eq!(Length::percent(10.0).to_absolute_user_units(), None);
+    }
+}
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
 use crate::error::Error;
 
@@ -35,8 +35,8 @@ impl AsValue for LengthAdjust {
     }
 }
 
-impl ToString for LengthAdjust {
-    fn to_string(&self) -> String {
-        self.as_value().to_string()
+impl Display for LengthAdjust {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_value())
     }
 }
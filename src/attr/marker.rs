@@ -0,0 +1,83 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::error::Error;
+
+use super::def_keyword;
+
+/// `orient` attribute of `<marker>`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/orient).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Orient {
+    /// Follow the direction of the path
+    Auto,
+    /// Follow the direction of the path, reversed at the start,
+    /// e.g. for arrows on both ends
+    AutoStartReverse,
+    /// Fixed angle in degrees
+    Angle(f32),
+}
+
+impl Default for Orient {
+    #[inline]
+    fn default() -> Self {
+        Self::Angle(0.0)
+    }
+}
+
+impl FromStr for Orient {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        match s {
+            "auto" => return Ok(Self::Auto),
+            "auto-start-reverse" => return Ok(Self::AutoStartReverse),
+            _ => {}
+        }
+
+        let (value, scale) = [("deg", 1.0), ("grad", 0.9), ("rad", 180.0 / std::f32::consts::PI), ("turn", 360.0)]
+            .into_iter()
+            .find_map(|(unit, scale)| Some((s.strip_suffix(unit)?, scale)))
+            .unwrap_or((s, 1.0));
+
+        Ok(Self::Angle(value.parse::<f32>()? * scale))
+    }
+}
+
+impl Display for Orient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => f.write_str("auto"),
+            Self::AutoStartReverse => f.write_str("auto-start-reverse"),
+            Self::Angle(angle) => write!(f, "{angle}"),
+        }
+    }
+}
+
+def_keyword! {
+    /// `markerUnits` attribute enumeration
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/markerUnits).
+    #[derive(Default)]
+    MarkerUnits {
+        /// Scaled by the stroke width of the referencing element
+        #[default]
+        StrokeWidth => "strokeWidth",
+        /// User space of the referencing element
+        UserSpaceOnUse => "userSpaceOnUse",
+    }
+}
+
+def_keyword! {
+    /// Vertices of a shape where a marker is drawn
+    MarkerPosition {
+        /// `marker-start`, on the first vertex
+        Start => "marker-start",
+        /// `marker-mid`, on every vertex but the first and last ones
+        Middle => "marker-mid",
+        /// `marker-end`, on the last vertex
+        End => "marker-end",
+    }
+}
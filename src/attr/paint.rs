@@ -0,0 +1,111 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::error::Error;
+
+use super::color::Color;
+
+/// Paint value for `fill` and `stroke`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Content_type#paint).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Paint {
+    /// `none`
+    None,
+    /// Solid color, including `currentColor`
+    Color(Color),
+    /// `url(#id)` reference to a paint server, with an optional fallback
+    /// used when the reference is invalid
+    Url {
+        url: String,
+        fallback: Option<Box<Paint>>,
+    },
+    /// `context-fill`
+    ContextFill,
+    /// `context-stroke`
+    ContextStroke,
+}
+
+impl Paint {
+    /// Reference a paint server, e.g. a gradient, by its `id`.
+    #[inline]
+    pub fn url(id: &str) -> Self {
+        Self::Url { url: format!("#{id}"), fallback: None }
+    }
+
+    /// Reference a paint server by its `id`, falling back to another paint.
+    #[inline]
+    pub fn url_or(id: &str, fallback: Paint) -> Self {
+        Self::Url { url: format!("#{id}"), fallback: Some(Box::new(fallback)) }
+    }
+}
+
+impl From<Color> for Paint {
+    #[inline]
+    fn from(value: Color) -> Self {
+        Self::Color(value)
+    }
+}
+
+impl FromStr for Paint {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        match s {
+            "none" => return Ok(Self::None),
+            "context-fill" => return Ok(Self::ContextFill),
+            "context-stroke" => return Ok(Self::ContextStroke),
+            _ => {}
+        }
+
+        if let Some(rest) = s.strip_prefix("url(") {
+            let end = rest.find(')').ok_or(Error::InvalidColor(s.len()))?;
+
+            let url = rest[..end].trim().trim_matches(|c| c == '"' || c == '\'').to_string();
+            let fallback = rest[end + 1..].trim();
+
+            let fallback = if fallback.is_empty() {
+                None
+            } else {
+                Some(Box::new(fallback.parse()?))
+            };
+
+            return Ok(Self::Url { url, fallback });
+        }
+
+        s.parse().map(Self::Color)
+    }
+}
+
+impl Display for Paint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Color(color) => write!(f, "{color}"),
+            Self::Url { url, fallback: None } => write!(f, "url({url})"),
+            Self::Url { url, fallback: Some(fallback) } => write!(f, "url({url}) {fallback}"),
+            Self::ContextFill => f.write_str("context-fill"),
+            Self::ContextStroke => f.write_str("context-stroke"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_and_serialize() {
+        for s in ["none", "context-fill", "context-stroke", "currentColor", "#123456", "url(#grad)", "url(#grad) red", "url(#a) none"] {
+            assert_eq!(s.parse::<Paint>().unwrap().to_string(), s);
+        }
+
+        assert_eq!("url('#grad')".parse::<Paint>().unwrap(), Paint::url("grad"));
+        assert_eq!(
+            "url(#grad) #f00".parse::<Paint>().unwrap(),
+            Paint::url_or("grad", Color::rgb(255, 0, 0).into()),
+        );
+        assert!("url(#grad".parse::<Paint>().is_err());
+    }
+}
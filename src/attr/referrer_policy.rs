@@ -1,7 +1,11 @@
 use crate::error::Error;
 
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
+/// Value of `referrerpolicy`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/referrerPolicy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ReferrerPolicy {
     NoReferrer,
     NoReferrerWhenDowngrade,
@@ -31,9 +35,9 @@ impl FromStr for ReferrerPolicy {
     }
 }
 
-impl ToString for ReferrerPolicy {
-    fn to_string(&self) -> String {
-        match self {
+impl Display for ReferrerPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
             Self::NoReferrer => "no-referrer",
             Self::NoReferrerWhenDowngrade => "no-referrer-when-downgrade",
             Self::Origin => "origin",
@@ -42,6 +46,6 @@ impl ToString for ReferrerPolicy {
             Self::StrictOrigin => "strict-origin",
             Self::StrictOriginWhenCrossOrigin => "strict-origin-when-cross-origin",
             Self::UnsafeUrl => "unsafe-url",
-        }.to_string()
+        })
     }
 }
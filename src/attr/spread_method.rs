@@ -0,0 +1,17 @@
+use super::def_keyword;
+
+def_keyword! {
+    /// `spreadMethod` attribute enumeration
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/spreadMethod).
+    #[derive(Default)]
+    SpreadMethod {
+        /// Extend the final colors
+        #[default]
+        Pad => "pad",
+        /// Repeat the gradient in reverse
+        Reflect => "reflect",
+        /// Repeat the gradient
+        Repeat => "repeat",
+    }
+}
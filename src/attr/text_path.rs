@@ -0,0 +1,43 @@
+use super::def_keyword;
+
+def_keyword! {
+    /// `method` attribute of `<textPath>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/method).
+    #[derive(Default)]
+    TextPathMethod {
+        /// Rotate glyphs along the path
+        #[default]
+        Align => "align",
+        /// Stretch glyphs along the path
+        Stretch => "stretch",
+    }
+}
+
+def_keyword! {
+    /// `spacing` attribute of `<textPath>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/spacing).
+    #[derive(Default)]
+    TextPathSpacing {
+        /// Let the user agent adjust spacing for a good look
+        Auto => "auto",
+        /// Keep the spacing of the font
+        #[default]
+        Exact => "exact",
+    }
+}
+
+def_keyword! {
+    /// `side` attribute of `<textPath>`
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/side).
+    #[derive(Default)]
+    TextPathSide {
+        /// Render text on the left of the path direction
+        #[default]
+        Left => "left",
+        /// Render text on the right, i.e. along the path reversed
+        Right => "right",
+    }
+}
@@ -0,0 +1,513 @@
+use std::{fmt::Display, ops::Mul, str::FromStr};
+
+use crate::{element::path::{PathCommandKind, PathCursor, PathData, SweepFlag}, error::Error, parser::scanner::Scanner, Point};
+
+/// 2D affine transformation matrix
+///
+/// Laid out as `matrix(a, b, c, d, e, f)`, which maps a point `(x, y)` to
+/// `(a * x + c * y + e, b * x + d * y + f)`.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform#matrix).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub e: f32,
+    pub f: f32,
+}
+
+impl Default for Matrix {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Matrix {
+    /// Create a matrix from its six components.
+    #[inline]
+    pub fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Self {
+        Self { a, b, c, d, e, f }
+    }
+
+    /// Identity matrix.
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Translation matrix.
+    #[inline]
+    pub fn translate(tx: f32, ty: f32) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, tx, ty)
+    }
+
+    /// Scaling matrix.
+    #[inline]
+    pub fn scale(sx: f32, sy: f32) -> Self {
+        Self::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// Rotation matrix around the origin, with the angle in degrees.
+    pub fn rotate(angle: f32) -> Self {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        Self::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// Skewing matrix along the X axis, with the angle in degrees.
+    #[inline]
+    pub fn skew_x(angle: f32) -> Self {
+        Self::new(1.0, 0.0, angle.to_radians().tan(), 1.0, 0.0, 0.0)
+    }
+
+    /// Skewing matrix along the Y axis, with the angle in degrees.
+    #[inline]
+    pub fn skew_y(angle: f32) -> Self {
+        Self::new(1.0, angle.to_radians().tan(), 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Apply the matrix to a point.
+    #[inline]
+    pub fn apply(&self, p: Point) -> Point {
+        Point(self.a * p.0 + self.c * p.1 + self.e, self.b * p.0 + self.d * p.1 + self.f)
+    }
+
+    /// Apply the matrix to a vector, ignoring the translation.
+    #[inline]
+    pub fn apply_vector(&self, v: Point) -> Point {
+        Point(self.a * v.0 + self.c * v.1, self.b * v.0 + self.d * v.1)
+    }
+
+    /// Determinant of the linear part.
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// Inverse matrix, or `None` if the matrix is not invertible.
+    pub fn inverse(&self) -> Option<Matrix> {
+        let det = self.determinant();
+
+        if det.abs() < f32::EPSILON {
+            return None;
+        }
+
+        Some(Matrix {
+            a: self.d / det,
+            b: -self.b / det,
+            c: -self.c / det,
+            d: self.a / det,
+            e: (self.c * self.f - self.d * self.e) / det,
+            f: (self.b * self.e - self.a * self.f) / det,
+        })
+    }
+
+    /// Apply the matrix to path data.
+    ///
+    /// The result is made of absolute commands. Horizontal and vertical lines
+    /// become straight lines unless the matrix keeps the axes aligned.
+    pub fn apply_to_path(&self, data: &PathData) -> PathData {
+        let mut cursor = PathCursor::new();
+
+        let m = |p: Point| self.apply(p);
+        let aligned = self.b == 0.0 && self.c == 0.0;
+
+        data.commands().iter()
+            .map(|cmd| match cursor.advance(cmd) {
+                PathCommandKind::MoveToAbs(to) => PathCommandKind::MoveToAbs(m(to)),
+                PathCommandKind::HorizontalAbs(x) if aligned => PathCommandKind::HorizontalAbs(self.a * x + self.e),
+                PathCommandKind::VerticalAbs(y) if aligned => PathCommandKind::VerticalAbs(self.d * y + self.f),
+                PathCommandKind::LineAbs(_) |
+                    PathCommandKind::HorizontalAbs(_) |
+                    PathCommandKind::VerticalAbs(_) => PathCommandKind::LineAbs(m(cursor.current())),
+                PathCommandKind::CubicBezierAbs { con_start, con_end, to } => PathCommandKind::CubicBezierAbs {
+                    con_start: m(con_start),
+                    con_end: m(con_end),
+                    to: m(to),
+                },
+                PathCommandKind::SmoothCubicBezierAbs { con_end, to } => PathCommandKind::SmoothCubicBezierAbs {
+                    con_end: m(con_end),
+                    to: m(to),
+                },
+                PathCommandKind::QuadraticBezierAbs { con, to } => PathCommandKind::QuadraticBezierAbs {
+                    con: m(con),
+                    to: m(to),
+                },
+                PathCommandKind::SmoothQuadraticBezierAbs(to) => PathCommandKind::SmoothQuadraticBezierAbs(m(to)),
+                PathCommandKind::EllipticalArcAbs { radius_x, radius_y, angle, laf, sf, shift } => {
+                    let (radius_x, radius_y, angle) = self.transform_ellipse(radius_x, radius_y, angle);
+
+                    // Mirroring reverses the direction of the arc
+                    let sf = match (sf, self.determinant() < 0.0) {
+                        (SweepFlag::Clockwise, true) => SweepFlag::CounterClockwise,
+                        (SweepFlag::CounterClockwise, true) => SweepFlag::Clockwise,
+                        (sf, false) => sf,
+                    };
+
+                    PathCommandKind::EllipticalArcAbs { radius_x, radius_y, angle, laf, sf, shift: m(shift) }
+                }
+                cmd => cmd,
+            })
+            .collect()
+    }
+
+    /// Radii and rotation (in degrees) of an ellipse mapped through the matrix.
+    fn transform_ellipse(&self, rx: f32, ry: f32, angle: f32) -> (f32, f32, f32) {
+        let (sin, cos) = angle.to_radians().sin_cos();
+
+        // Columns of the matrix mapping the unit circle onto the ellipse
+        let u = self.apply_vector(Point(rx.abs() * cos, rx.abs() * sin));
+        let v = self.apply_vector(Point(-ry.abs() * sin, ry.abs() * cos));
+
+        // Closed-form singular value decomposition of [u v]
+        let e = (u.0 + v.1) / 2.0;
+        let f = (u.0 - v.1) / 2.0;
+        let g = (u.1 + v.0) / 2.0;
+        let h = (u.1 - v.0) / 2.0;
+
+        let q = e.hypot(h);
+        let r = f.hypot(g);
+
+        let theta = (g.atan2(f) + h.atan2(e)) / 2.0;
+
+        (q + r, (q - r).abs(), theta.to_degrees())
+    }
+}
+
+impl Mul for Matrix {
+    type Output = Matrix;
+
+    /// Compose two matrices, where `rhs` is applied first.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Matrix {
+            a: self.a * rhs.a + self.c * rhs.b,
+            b: self.b * rhs.a + self.d * rhs.b,
+            c: self.a * rhs.c + self.c * rhs.d,
+            d: self.b * rhs.c + self.d * rhs.d,
+            e: self.a * rhs.e + self.c * rhs.f + self.e,
+            f: self.b * rhs.e + self.d * rhs.f + self.f,
+        }
+    }
+}
+
+impl Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "matrix({} {} {} {} {} {})", self.a, self.b, self.c, self.d, self.e, self.f)
+    }
+}
+
+/// Transform function enumeration
+///
+/// Angles are in degrees.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransformFunction {
+    /// `matrix(a, b, c, d, e, f)`
+    Matrix(Matrix),
+    /// `translate(x, y)`
+    Translate(f32, f32),
+    /// `scale(x, y)`
+    Scale(f32, f32),
+    /// `rotate(angle)`, or `rotate(angle, cx, cy)` with a center
+    Rotate {
+        angle: f32,
+        center: Option<Point>,
+    },
+    /// `skewX(angle)`
+    SkewX(f32),
+    /// `skewY(angle)`
+    SkewY(f32),
+}
+
+impl TransformFunction {
+    /// Matrix of the function.
+    pub fn to_matrix(&self) -> Matrix {
+        match *self {
+            Self::Matrix(m) => m,
+            Self::Translate(tx, ty) => Matrix::translate(tx, ty),
+            Self::Scale(sx, sy) => Matrix::scale(sx, sy),
+            Self::Rotate { angle, center: None } => Matrix::rotate(angle),
+            Self::Rotate { angle, center: Some(c) } => Matrix::translate(c.0, c.1) * Matrix::rotate(angle) * Matrix::translate(-c.0, -c.1),
+            Self::SkewX(angle) => Matrix::skew_x(angle),
+            Self::SkewY(angle) => Matrix::skew_y(angle),
+        }
+    }
+
+    /// Function undoing the current one, or `None` if it is not invertible.
+    pub fn inverse(&self) -> Option<TransformFunction> {
+        Some(match *self {
+            Self::Matrix(m) => Self::Matrix(m.inverse()?),
+            Self::Translate(tx, ty) => Self::Translate(-tx, -ty),
+            Self::Scale(sx, sy) if sx != 0.0 && sy != 0.0 => Self::Scale(1.0 / sx, 1.0 / sy),
+            Self::Scale(..) => return None,
+            Self::Rotate { angle, center } => Self::Rotate { angle: -angle, center },
+            Self::SkewX(angle) => Self::SkewX(-angle),
+            Self::SkewY(angle) => Self::SkewY(-angle),
+        })
+    }
+}
+
+impl Display for TransformFunction {
+    /// Write the function in its most compact form.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Matrix(m) => write!(f, "{m}"),
+            Self::Translate(tx, 0.0) => write!(f, "translate({tx})"),
+            Self::Translate(tx, ty) => write!(f, "translate({tx} {ty})"),
+            Self::Scale(sx, sy) if sx == sy => write!(f, "scale({sx})"),
+            Self::Scale(sx, sy) => write!(f, "scale({sx} {sy})"),
+            Self::Rotate { angle, center: None } => write!(f, "rotate({angle})"),
+            Self::Rotate { angle, center: Some(c) } => write!(f, "rotate({angle} {} {})", c.0, c.1),
+            Self::SkewX(angle) => write!(f, "skewX({angle})"),
+            Self::SkewY(angle) => write!(f, "skewY({angle})"),
+        }
+    }
+}
+
+/// Transform list, the value of `transform`
+///
+/// Functions apply from the last to the first one, as they do in SVG.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Transform {
+    functions: Vec<TransformFunction>,
+}
+
+impl Transform {
+    /// Create an empty transform list.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Access the function list.
+    #[inline]
+    pub fn functions(&self) -> &[TransformFunction] {
+        &self.functions
+    }
+
+    /// Append a function.
+    pub fn push(&mut self, function: TransformFunction) -> &mut Self {
+        self.functions.push(function);
+        self
+    }
+
+    pub fn matrix(&mut self, matrix: Matrix) -> &mut Self {
+        self.push(TransformFunction::Matrix(matrix))
+    }
+
+    pub fn translate(&mut self, tx: f32, ty: f32) -> &mut Self {
+        self.push(TransformFunction::Translate(tx, ty))
+    }
+
+    pub fn scale(&mut self, sx: f32, sy: f32) -> &mut Self {
+        self.push(TransformFunction::Scale(sx, sy))
+    }
+
+    pub fn rotate(&mut self, angle: f32) -> &mut Self {
+        self.push(TransformFunction::Rotate { angle, center: None })
+    }
+
+    pub fn rotate_around(&mut self, angle: f32, center: Point) -> &mut Self {
+        self.push(TransformFunction::Rotate { angle, center: Some(center) })
+    }
+
+    pub fn skew_x(&mut self, angle: f32) -> &mut Self {
+        self.push(TransformFunction::SkewX(angle))
+    }
+
+    pub fn skew_y(&mut self, angle: f32) -> &mut Self {
+        self.push(TransformFunction::SkewY(angle))
+    }
+
+    /// Compose the list into a single matrix.
+    pub fn to_matrix(&self) -> Matrix {
+        self.functions.iter().fold(Matrix::identity(), |m, function| m * function.to_matrix())
+    }
+
+    /// Transform list undoing the current one, or `None` if it is not invertible.
+    pub fn inverse(&self) -> Option<Transform> {
+        Some(Transform {
+            functions: self.functions.iter()
+                .rev()
+                .map(TransformFunction::inverse)
+                .collect::<Option<Vec<_>>>()?,
+        })
+    }
+
+    /// Apply the transform list to a point.
+    #[inline]
+    pub fn apply(&self, p: Point) -> Point {
+        self.to_matrix().apply(p)
+    }
+
+    /// Apply the transform list to path data.
+    ///
+    /// See [`Matrix::apply_to_path`].
+    #[inline]
+    pub fn apply_to_path(&self, data: &PathData) -> PathData {
+        self.to_matrix().apply_to_path(data)
+    }
+}
+
+impl FromIterator<TransformFunction> for Transform {
+    fn from_iter<T: IntoIterator<Item = TransformFunction>>(iter: T) -> Self {
+        Self {
+            functions: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, function) in self.functions.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+
+            write!(f, "{function}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Read the arguments of a transform function, between the parentheses.
+fn arguments(scanner: &mut Scanner) -> Result<Vec<f32>, Error> {
+    let mut args = Vec::with_capacity(6);
+
+    scanner.skip_wsp();
+    scanner.expect(b'(')?;
+    scanner.skip_wsp();
+
+    while scanner.peek() != Some(b')') {
+        args.push(scanner.number()?);
+        scanner.skip_comma_wsp();
+    }
+
+    scanner.expect(b')')?;
+
+    Ok(args)
+}
+
+impl FromStr for Transform {
+    type Err = Error;
+
+    /// Parse a transform list.
+    ///
+    /// On malformed input, the error carries the byte offset
+    /// where parsing has failed.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(s, Error::InvalidTransform);
+        let mut transform = Transform::new();
+
+        scanner.skip_wsp();
+
+        while scanner.peek().is_some() {
+            let start = scanner.pos();
+            let name = scanner.ident();
+            let args = arguments(&mut scanner)?;
+
+            transform.push(match (name, args.as_slice()) {
+                ("matrix", &[a, b, c, d, e, f]) => TransformFunction::Matrix(Matrix::new(a, b, c, d, e, f)),
+                ("translate", &[tx]) => TransformFunction::Translate(tx, 0.0),
+                ("translate", &[tx, ty]) => TransformFunction::Translate(tx, ty),
+                ("scale", &[s]) => TransformFunction::Scale(s, s),
+                ("scale", &[sx, sy]) => TransformFunction::Scale(sx, sy),
+                ("rotate", &[angle]) => TransformFunction::Rotate { angle, center: None },
+                ("rotate", &[angle, cx, cy]) => TransformFunction::Rotate { angle, center: Some(Point(cx, cy)) },
+                ("skewX", &[angle]) => TransformFunction::SkewX(angle),
+                ("skewY", &[angle]) => TransformFunction::SkewY(angle),
+                _ => return Err(Error::InvalidTransform(start)),
+            });
+
+            scanner.skip_comma_wsp();
+        }
+
+        Ok(transform)
+    }
+}
+
+impl FromStr for Matrix {
+    type Err = Error;
+
+    /// Parse a transform list into the matrix it composes to.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse::<Transform>()?.to_matrix())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_point(actual: Point, expected: Point) {
+        assert!(actual.distance(&expected) < 1e-4, "{actual:?} != {expected:?}");
+    }
+
+    #[test]
+    fn parse_and_serialize() {
+        let transform: Transform = "translate(10,0)scale(2 2) , rotate(-45 1 1)\nskewX(.5)".parse().unwrap();
+        assert_eq!(transform.to_string(), "translate(10) scale(2) rotate(-45 1 1) skewX(0.5)");
+
+        assert!(matches!("translate(1) spin(2)".parse::<Transform>(), Err(Error::InvalidTransform(13))));
+        assert!(matches!("rotate(1 2)".parse::<Transform>(), Err(Error::InvalidTransform(0))));
+    }
+
+    #[test]
+    fn compose_and_invert() {
+        let mut transform = Transform::new();
+        transform.translate(10.0, 5.0).rotate_around(90.0, Point(1.0, 0.0)).scale(2.0, 3.0);
+
+        let p = Point(1.0, 1.0);
+        let q = transform.apply(p);
+        assert_point(q, Point(8.0, 6.0));
+
+        let inverse = transform.inverse().unwrap();
+        assert_eq!(inverse.to_string(), "scale(0.5 0.33333334) rotate(-90 1 0) translate(-10 -5)");
+        assert_point(inverse.apply(q), p);
+        assert_point(transform.to_matrix().inverse().unwrap().apply(q), p);
+    }
+
+    #[test]
+    fn transform_path() {
+        let data: PathData = "M 0 0 h 10 a 10 5 0 0 1 10 10".parse().unwrap();
+
+        let mut transform = Transform::new();
+        transform.rotate(90.0);
+        let rotated = transform.apply_to_path(&data);
+
+        let PathCommandKind::EllipticalArcAbs { radius_x, radius_y, angle, shift, .. } = rotated.commands()[2] else {
+            panic!("arc expected");
+        };
+
+        assert_point(Point(radius_x, radius_y), Point(10.0, 5.0));
+        assert!((angle - 90.0).abs() < 1e-3);
+        assert_point(shift, Point(-10.0, 20.0));
+
+        let mut mirror = Transform::new();
+        mirror.scale(1.0, -1.0);
+
+        assert_eq!(mirror.apply_to_path(&data).to_string(), "M 0,0 H 10 A 10 5 0 0 0 20,-10");
+    }
+
+    #[cfg(feature = "attr-presentation")]
+    #[test]
+    fn set_on_element() {
+        use crate::{attr::{AccessAttr, Attr}, element::Rect};
+
+        let mut transform = Transform::new();
+        transform.translate(1.0, 2.0).skew_y(30.0);
+
+        let mut rect = Rect::default();
+        rect.set_transform(&transform);
+
+        assert_eq!(rect.attr(&Attr::Transform), Some("translate(1 2) skewY(30)"));
+        assert_eq!(rect.transform().unwrap().unwrap(), transform);
+    }
+}
@@ -0,0 +1,17 @@
+use super::def_keyword;
+
+def_keyword! {
+    /// Coordinate system of attributes such as `gradientUnits` and `clipPathUnits`
+    ///
+    /// There is no default: `gradientUnits`, `filterUnits` and `maskUnits`
+    /// fall back to `objectBoundingBox`, while `clipPathUnits`,
+    /// `maskContentUnits` and `primitiveUnits` fall back to `userSpaceOnUse`.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/gradientUnits).
+    Units {
+        /// User space of the referencing element
+        UserSpaceOnUse => "userSpaceOnUse",
+        /// Fractions of the bounding box of the referencing element
+        ObjectBoundingBox => "objectBoundingBox",
+    }
+}
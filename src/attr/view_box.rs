@@ -0,0 +1,329 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{bbox::BoundingBox, error::Error, parser::scanner::Scanner};
+
+use super::{transform::Matrix, AsValue};
+
+/// Value of `viewBox`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/viewBox).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ViewBox {
+    pub min_x: f32,
+    pub min_y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl ViewBox {
+    #[inline]
+    pub fn new(min_x: f32, min_y: f32, width: f32, height: f32) -> Self {
+        Self { min_x, min_y, width, height }
+    }
+
+    /// Compute the transform from the user space of the view box into
+    /// a viewport of the given size, placed at the origin.
+    ///
+    /// Returns `None` if the view box is empty, in which case
+    /// the element is not rendered.
+    ///
+    /// See [the specification](https://www.w3.org/TR/SVG2/coords.html#ComputingAViewportsTransform).
+    pub fn viewport_transform(&self, aspect: &PreserveAspectRatio, width: f32, height: f32) -> Option<Matrix> {
+        if self.width <= 0.0 || self.height <= 0.0 {
+            return None;
+        }
+
+        let mut scale_x = width / self.width;
+        let mut scale_y = height / self.height;
+
+        if aspect.align != Align::None {
+            let scale = match aspect.meet_or_slice {
+                MeetOrSlice::Meet => scale_x.min(scale_y),
+                MeetOrSlice::Slice => scale_x.max(scale_y),
+            };
+
+            scale_x = scale;
+            scale_y = scale;
+        }
+
+        let mut translate_x = -self.min_x * scale_x;
+        let mut translate_y = -self.min_y * scale_y;
+
+        let (align_x, align_y) = aspect.align.factors();
+
+        translate_x += (width - self.width * scale_x) * align_x;
+        translate_y += (height - self.height * scale_y) * align_y;
+
+        Some(Matrix::new(scale_x, 0.0, 0.0, scale_y, translate_x, translate_y))
+    }
+}
+
+impl From<BoundingBox> for ViewBox {
+    #[inline]
+    fn from(value: BoundingBox) -> Self {
+        Self::new(value.x(), value.y(), value.width(), value.height())
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for ViewBox {
+    #[inline]
+    fn from((min_x, min_y, width, height): (f32, f32, f32, f32)) -> Self {
+        Self::new(min_x, min_y, width, height)
+    }
+}
+
+impl FromStr for ViewBox {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(s, Error::InvalidViewBox);
+        let mut values = [0.0; 4];
+
+        scanner.skip_wsp();
+
+        for (i, value) in values.iter_mut().enumerate() {
+            if i > 0 {
+                scanner.skip_comma_wsp();
+            }
+
+            let pos = scanner.pos();
+            *value = scanner.number()?;
+
+            // Negative sizes are an error
+            if i >= 2 && *value < 0.0 {
+                return Err(Error::InvalidViewBox(pos));
+            }
+        }
+
+        scanner.skip_wsp();
+
+        if scanner.peek().is_some() {
+            return Err(scanner.error());
+        }
+
+        let [min_x, min_y, width, height] = values;
+
+        Ok(Self { min_x, min_y, width, height })
+    }
+}
+
+impl Display for ViewBox {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {} {}", self.min_x, self.min_y, self.width, self.height)
+    }
+}
+
+/// Alignment of `preserveAspectRatio`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/preserveAspectRatio).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Align {
+    /// `none`, i.e. scale non-uniformly
+    None,
+    XMinYMin,
+    XMidYMin,
+    XMaxYMin,
+    XMinYMid,
+    #[default]
+    XMidYMid,
+    XMaxYMid,
+    XMinYMax,
+    XMidYMax,
+    XMaxYMax,
+}
+
+impl Align {
+    /// Fractions of the free space placed before the view box,
+    /// horizontally and vertically.
+    fn factors(&self) -> (f32, f32) {
+        match self {
+            Self::None | Self::XMinYMin => (0.0, 0.0),
+            Self::XMidYMin => (0.5, 0.0),
+            Self::XMaxYMin => (1.0, 0.0),
+            Self::XMinYMid => (0.0, 0.5),
+            Self::XMidYMid => (0.5, 0.5),
+            Self::XMaxYMid => (1.0, 0.5),
+            Self::XMinYMax => (0.0, 1.0),
+            Self::XMidYMax => (0.5, 1.0),
+            Self::XMaxYMax => (1.0, 1.0),
+        }
+    }
+}
+
+impl FromStr for Align {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "xMinYMin" => Ok(Self::XMinYMin),
+            "xMidYMin" => Ok(Self::XMidYMin),
+            "xMaxYMin" => Ok(Self::XMaxYMin),
+            "xMinYMid" => Ok(Self::XMinYMid),
+            "xMidYMid" => Ok(Self::XMidYMid),
+            "xMaxYMid" => Ok(Self::XMaxYMid),
+            "xMinYMax" => Ok(Self::XMinYMax),
+            "xMidYMax" => Ok(Self::XMidYMax),
+            "xMaxYMax" => Ok(Self::XMaxYMax),
+            _ => Err(Error::NoOptionFound),
+        }
+    }
+}
+
+impl AsValue for Align {
+    fn as_value(&self) -> &str {
+        match self {
+            Self::None => "none",
+            Self::XMinYMin => "xMinYMin",
+            Self::XMidYMin => "xMidYMin",
+            Self::XMaxYMin => "xMaxYMin",
+            Self::XMinYMid => "xMinYMid",
+            Self::XMidYMid => "xMidYMid",
+            Self::XMaxYMid => "xMaxYMid",
+            Self::XMinYMax => "xMinYMax",
+            Self::XMidYMax => "xMidYMax",
+            Self::XMaxYMax => "xMaxYMax",
+        }
+    }
+}
+
+impl Display for Align {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_value())
+    }
+}
+
+/// Scaling strategy of `preserveAspectRatio`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/preserveAspectRatio).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MeetOrSlice {
+    /// Scale to fit entirely within the viewport
+    #[default]
+    Meet,
+    /// Scale to cover the entire viewport
+    Slice,
+}
+
+impl FromStr for MeetOrSlice {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "meet" => Ok(Self::Meet),
+            "slice" => Ok(Self::Slice),
+            _ => Err(Error::NoOptionFound),
+        }
+    }
+}
+
+impl AsValue for MeetOrSlice {
+    fn as_value(&self) -> &str {
+        match self {
+            Self::Meet => "meet",
+            Self::Slice => "slice",
+        }
+    }
+}
+
+impl Display for MeetOrSlice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_value())
+    }
+}
+
+/// Value of `preserveAspectRatio`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/preserveAspectRatio).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PreserveAspectRatio {
+    pub align: Align,
+    pub meet_or_slice: MeetOrSlice,
+}
+
+impl PreserveAspectRatio {
+    #[inline]
+    pub fn new(align: Align, meet_or_slice: MeetOrSlice) -> Self {
+        Self { align, meet_or_slice }
+    }
+}
+
+impl From<Align> for PreserveAspectRatio {
+    #[inline]
+    fn from(value: Align) -> Self {
+        Self::new(value, MeetOrSlice::default())
+    }
+}
+
+impl FromStr for PreserveAspectRatio {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_ascii_whitespace();
+
+        let mut align = parts.next().ok_or(Error::NoOptionFound)?;
+
+        // `defer` only applies to `<image>` in SVG 1.1 and is dropped
+        if align == "defer" {
+            align = parts.next().ok_or(Error::NoOptionFound)?;
+        }
+
+        let align = align.parse()?;
+        let meet_or_slice = parts.next().map(str::parse).transpose()?.unwrap_or_default();
+
+        if parts.next().is_some() {
+            return Err(Error::NoOptionFound);
+        }
+
+        Ok(Self { align, meet_or_slice })
+    }
+}
+
+impl Display for PreserveAspectRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.meet_or_slice {
+            MeetOrSlice::Meet => write!(f, "{}", self.align),
+            MeetOrSlice::Slice => write!(f, "{} {}", self.align, self.meet_or_slice),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Point;
+
+    use super::*;
+
+    #[test]
+    fn parse_and_serialize() {
+        assert_eq!("0,0, 100 50.5".parse::<ViewBox>().unwrap(), ViewBox::new(0.0, 0.0, 100.0, 50.5));
+        assert_eq!(ViewBox::new(-1.0, 2.0, 3.0, 4.0).to_string(), "-1 2 3 4");
+        assert!(matches!("0 0 -1 1".parse::<ViewBox>(), Err(Error::InvalidViewBox(4))));
+        assert!(matches!("0 0 1".parse::<ViewBox>(), Err(Error::InvalidViewBox(5))));
+
+        for s in ["xMidYMid", "none", "xMinYMax slice"] {
+            assert_eq!(s.parse::<PreserveAspectRatio>().unwrap().to_string(), s);
+        }
+
+        assert_eq!("defer xMaxYMin meet".parse::<PreserveAspectRatio>().unwrap(), Align::XMaxYMin.into());
+        assert!("xMidYMid meet slice".parse::<PreserveAspectRatio>().is_err());
+    }
+
+    #[test]
+    fn compute_viewport_transform() {
+        let view_box = ViewBox::new(10.0, 10.0, 100.0, 50.0);
+
+        let meet = view_box.viewport_transform(&PreserveAspectRatio::default(), 200.0, 200.0).unwrap();
+        assert_eq!(meet.apply(Point(10.0, 10.0)), Point(0.0, 50.0));
+        assert_eq!(meet.apply(Point(110.0, 60.0)), Point(200.0, 150.0));
+
+        let slice = PreserveAspectRatio::new(Align::XMinYMin, MeetOrSlice::Slice);
+        let slice = view_box.viewport_transform(&slice, 200.0, 200.0).unwrap();
+        assert_eq!(slice.apply(Point(110.0, 60.0)), Point(400.0, 200.0));
+
+        let stretch = view_box.viewport_transform(&Align::None.into(), 200.0, 200.0).unwrap();
+        assert_eq!(stretch.apply(Point(110.0, 60.0)), Point(200.0, 200.0));
+
+        assert!(ViewBox::new(0.0, 0.0, 0.0, 10.0).viewport_transform(&Default::default(), 1.0, 1.0).is_none());
+    }
+}
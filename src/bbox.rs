@@ -0,0 +1,547 @@
+use std::{cell::RefCell, collections::HashMap, f32::consts::{PI, TAU}};
+
+use crate::{attr::{length::Length, transform::Matrix, AccessAttr, Attr}, element::{path::{PathCommandKind, PathCursor, PathData, SweepFlag}, ChildKind, ChildList, Children, ElementKind, Use}, Point};
+
+/// Axis-aligned bounding box
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    min: Point,
+    max: Point,
+}
+
+impl BoundingBox {
+    /// Create a bounding box spanning two corners.
+    pub fn new(a: Point, b: Point) -> Self {
+        Self {
+            min: Point(a.0.min(b.0), a.1.min(b.1)),
+            max: Point(a.0.max(b.0), a.1.max(b.1)),
+        }
+    }
+
+    /// Smallest bounding box containing all the points.
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let mut bounds = None;
+
+        for p in points {
+            extend(&mut bounds, p);
+        }
+
+        bounds
+    }
+
+    /// Top-left corner.
+    #[inline]
+    pub fn min(&self) -> Point {
+        self.min
+    }
+
+    /// Bottom-right corner.
+    #[inline]
+    pub fn max(&self) -> Point {
+        self.max
+    }
+
+    #[inline]
+    pub fn x(&self) -> f32 {
+        self.min.0
+    }
+
+    #[inline]
+    pub fn y(&self) -> f32 {
+        self.min.1
+    }
+
+    #[inline]
+    pub fn width(&self) -> f32 {
+        self.max.0 - self.min.0
+    }
+
+    #[inline]
+    pub fn height(&self) -> f32 {
+        self.max.1 - self.min.1
+    }
+
+    /// Grow the bounding box to contain the point.
+    pub fn include(&mut self, p: Point) {
+        self.min = Point(self.min.0.min(p.0), self.min.1.min(p.1));
+        self.max = Point(self.max.0.max(p.0), self.max.1.max(p.1));
+    }
+
+    /// Smallest bounding box containing both.
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        BoundingBox {
+            min: Point(self.min.0.min(other.min.0), self.min.1.min(other.min.1)),
+            max: Point(self.max.0.max(other.max.0), self.max.1.max(other.max.1)),
+        }
+    }
+}
+
+/// Instance having geometric bounds.
+///
+/// Bounds are exact for the geometry and do not account for stroke width.
+pub trait BBox {
+    /// Bounding box in the user space of the parent element,
+    /// with the own `transform` applied.
+    ///
+    /// Returns `None` if the instance has no geometry, or if its
+    /// geometry cannot be resolved.
+    fn bbox(&self) -> Option<BoundingBox> {
+        self.transformed_bbox(&Matrix::identity())
+    }
+
+    /// Bounding box after mapping the geometry through `matrix`.
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox>;
+}
+
+/// Internal helper macro for implementing [`BBox`] for an element
+/// which is never rendered by itself.
+macro_rules! impl_no_bbox {
+    (filter_primitive: $name:ident) => {
+        crate::bbox::impl_no_bbox!($name, "Filter primitives are not rendered by themselves.");
+    };
+
+    (animation: $name:ident) => {
+        crate::bbox::impl_no_bbox!($name, "Animations are not rendered.");
+    };
+
+    (light_source: $name:ident) => {
+        crate::bbox::impl_no_bbox!($name, "Light sources are not rendered by themselves.");
+    };
+
+    ($name:ident, $doc:literal) => {
+        impl crate::bbox::BBox for $name {
+            #[doc = $doc]
+            #[inline]
+            fn transformed_bbox(&self, _: &crate::attr::transform::Matrix) -> Option<crate::bbox::BoundingBox> {
+                None
+            }
+        }
+    };
+}
+
+pub(crate) use impl_no_bbox;
+
+pub(crate) fn extend(bounds: &mut Option<BoundingBox>, p: Point) {
+    match bounds {
+        Some(bounds) => bounds.include(p),
+        None => *bounds = Some(BoundingBox::new(p, p)),
+    }
+}
+
+/// Compose `matrix` with the own `transform` of the element.
+///
+/// Returns `None` if the `transform` attribute is malformed.
+pub(crate) fn local_matrix<T>(el: &T, matrix: &Matrix) -> Option<Matrix>
+where
+    T: AccessAttr,
+{
+    // Looking up by key also finds the attribute without `attr-presentation`
+    match el.attr(&Attr::from("transform")) {
+        Some(value) => Some(*matrix * value.parse::<Matrix>().ok()?),
+        None => Some(*matrix),
+    }
+}
+
+/// Union of the bounding boxes of child elements.
+pub(crate) fn children_bbox(children: Option<&ChildList>, matrix: &Matrix) -> Option<BoundingBox> {
+    children?.iter()
+        .filter_map(|child| match child {
+            ChildKind::Element(el) => el.transformed_bbox(matrix),
+            ChildKind::String(_) => None,
+        })
+        .reduce(|a, b| a.union(&b))
+}
+
+/// Elements of a document by `id`, for resolving `<use>` references
+pub(crate) struct References<'a> {
+    ids: HashMap<&'a str, &'a ElementKind>,
+    // Ids being resolved, to stop at cyclic references
+    resolving: RefCell<Vec<&'a str>>,
+}
+
+impl<'a> References<'a> {
+    /// Collect the elements having an `id` in the tree.
+    pub(crate) fn collect(children: Option<&'a ChildList>) -> Self {
+        let mut refs = Self {
+            ids: HashMap::new(),
+            resolving: RefCell::new(Vec::new()),
+        };
+
+        refs.insert_all(children);
+        refs
+    }
+
+    fn insert_all(&mut self, children: Option<&'a ChildList>) {
+        for child in children.into_iter().flatten() {
+            if let ChildKind::Element(el) = child {
+                // The first element wins over duplicated ids
+                if let Some(id) = el.attr(&Attr::from("id")) {
+                    self.ids.entry(id).or_insert(el);
+                }
+
+                self.insert_all(el.children());
+            }
+        }
+    }
+
+    /// Bounding box of the element referenced by a `<use>`, placed at its `x` and `y`.
+    fn use_bbox(&self, el: &Use, matrix: &Matrix) -> Option<BoundingBox> {
+        let (&id, &target) = self.ids.get_key_value(el.href()?.strip_prefix('#')?)?;
+
+        if self.resolving.borrow().contains(&id) {
+            return None;
+        }
+
+        let x = el.x().unwrap_or_default().to_absolute_user_units()?;
+        let y = el.y().unwrap_or_default().to_absolute_user_units()?;
+
+        let matrix = local_matrix(el, matrix)? * Matrix::translate(x, y);
+
+        self.resolving.borrow_mut().push(id);
+
+        let bbox = match target {
+            // Symbols are only rendered through `<use>`, which sizes their viewport
+            ElementKind::Symbol(symbol) => {
+                let width = el.width().or(symbol.width());
+                let height = el.height().or(symbol.height());
+
+                let matrix = match (symbol.view_box(), width, height) {
+                    (Some(view_box), Some(width), Some(height)) => {
+                        let aspect = symbol.preserve_aspect_ratio().unwrap_or_default();
+                        matrix * view_box.viewport_transform(&aspect, width.to_absolute_user_units()?, height.to_absolute_user_units()?)?
+                    }
+                    _ => matrix,
+                };
+
+                resolved_children_bbox(symbol.children(), &matrix, self)
+            }
+            target => resolved_bbox(target, &matrix, self),
+        };
+
+        self.resolving.borrow_mut().pop();
+
+        bbox
+    }
+}
+
+/// Bounding box of an element, resolving `<use>` in its subtree through `refs`.
+pub(crate) fn resolved_bbox(el: &ElementKind, matrix: &Matrix, refs: &References) -> Option<BoundingBox> {
+    match el {
+        ElementKind::A(inner) => resolved_children_bbox(inner.children(), &local_matrix(inner, matrix)?, refs),
+        ElementKind::Group(inner) => resolved_children_bbox(inner.children(), &local_matrix(inner, matrix)?, refs),
+        ElementKind::Svg(inner) => resolved_children_bbox(inner.children(), &(local_matrix(inner, matrix)? * inner.content_matrix()?), refs),
+        ElementKind::Use(inner) => refs.use_bbox(inner, matrix),
+        el => el.transformed_bbox(matrix),
+    }
+}
+
+/// Union of the bounding boxes of child elements, resolving `<use>` through `refs`.
+pub(crate) fn resolved_children_bbox(children: Option<&ChildList>, matrix: &Matrix, refs: &References) -> Option<BoundingBox> {
+    children?.iter()
+        .filter_map(|child| match child {
+            ChildKind::Element(el) => resolved_bbox(el, matrix, refs),
+            ChildKind::String(_) => None,
+        })
+        .reduce(|a, b| a.union(&b))
+}
+
+/// Bounding box of a viewport, e.g. of `<image>`, which is not rendered
+/// without a positive size.
+pub(crate) fn viewport_bbox(matrix: &Matrix, x: Option<Length>, y: Option<Length>, width: Option<Length>, height: Option<Length>) -> Option<BoundingBox> {
+    let x = x.unwrap_or_default().to_absolute_user_units()?;
+    let y = y.unwrap_or_default().to_absolute_user_units()?;
+    let width = width?.to_absolute_user_units()?;
+    let height = height?.to_absolute_user_units()?;
+
+    if width <= 0.0 || height <= 0.0 {
+        return None;
+    }
+
+    BoundingBox::from_points([Point(x, y), Point(x + width, y), Point(x + width, y + height), Point(x, y + height)].map(|p| matrix.apply(p)))
+}
+
+/// Bounding box of a full ellipse.
+pub(crate) fn ellipse_bbox(matrix: &Matrix, center: Point, rx: f32, ry: f32) -> Option<BoundingBox> {
+    if rx <= 0.0 || ry <= 0.0 {
+        return None;
+    }
+
+    // x(t) = cx + u.x * cos(t) + v.x * sin(t), which spans `hypot(u.x, v.x)` around cx
+    let c = matrix.apply(center);
+    let u = matrix.apply_vector(Point(rx, 0.0));
+    let v = matrix.apply_vector(Point(0.0, ry));
+
+    let half = Point(u.0.hypot(v.0), u.1.hypot(v.1));
+
+    Some(BoundingBox::new(c - half, c + half))
+}
+
+/// Solve `a * t^2 + b * t + c = 0` for `t` in `(0, 1)`.
+fn unit_roots(a: f32, b: f32, c: f32) -> Vec<f32> {
+    const EPSILON: f32 = 1e-12;
+
+    let roots = if a.abs() < EPSILON {
+        if b.abs() < EPSILON {
+            vec![]
+        } else {
+            vec![-c / b]
+        }
+    } else {
+        let disc = b * b - 4.0 * a * c;
+
+        if disc < 0.0 {
+            vec![]
+        } else {
+            let sqrt = disc.sqrt();
+            vec![(-b + sqrt) / (2.0 * a), (-b - sqrt) / (2.0 * a)]
+        }
+    };
+
+    roots.into_iter().filter(|t| *t > 0.0 && *t < 1.0).collect()
+}
+
+fn cubic_point(p: [Point; 4], t: f32) -> Point {
+    let mt = 1.0 - t;
+    p[0] * (mt * mt * mt) + p[1] * (3.0 * mt * mt * t) + p[2] * (3.0 * mt * t * t) + p[3] * (t * t * t)
+}
+
+fn extend_cubic(bounds: &mut Option<BoundingBox>, p: [Point; 4]) {
+    extend(bounds, p[0]);
+    extend(bounds, p[3]);
+
+    // B'(t) / 3 = a * t^2 + b * t + c on each axis
+    let axes = [
+        (p[3].0 - 3.0 * p[2].0 + 3.0 * p[1].0 - p[0].0, 2.0 * (p[2].0 - 2.0 * p[1].0 + p[0].0), p[1].0 - p[0].0),
+        (p[3].1 - 3.0 * p[2].1 + 3.0 * p[1].1 - p[0].1, 2.0 * (p[2].1 - 2.0 * p[1].1 + p[0].1), p[1].1 - p[0].1),
+    ];
+
+    for (a, b, c) in axes {
+        for t in unit_roots(a, b, c) {
+            extend(bounds, cubic_point(p, t));
+        }
+    }
+}
+
+fn extend_quadratic(bounds: &mut Option<BoundingBox>, p: [Point; 3]) {
+    extend(bounds, p[0]);
+    extend(bounds, p[2]);
+
+    // B'(t) / 2 = (p0 - 2 * p1 + p2) * t + (p1 - p0) on each axis
+    let axes = [
+        (p[0].0 - 2.0 * p[1].0 + p[2].0, p[1].0 - p[0].0),
+        (p[0].1 - 2.0 * p[1].1 + p[2].1, p[1].1 - p[0].1),
+    ];
+
+    for (b, c) in axes {
+        for t in unit_roots(0.0, b, c) {
+            let mt = 1.0 - t;
+            extend(bounds, p[0] * (mt * mt) + p[1] * (2.0 * mt * t) + p[2] * (t * t));
+        }
+    }
+}
+
+/// Angle from vector `u` to vector `v`, in radians.
+fn vector_angle(u: Point, v: Point) -> f32 {
+    (u.0 * v.1 - u.1 * v.0).atan2(u.0 * v.0 + u.1 * v.1)
+}
+
+/// Extend bounds with an elliptical arc given in endpoint parameterization.
+///
+/// See [SVG implementation notes](https://www.w3.org/TR/SVG2/implnote.html#ArcConversionEndpointToCenter).
+#[allow(clippy::too_many_arguments)]
+fn extend_arc(bounds: &mut Option<BoundingBox>, matrix: &Matrix, from: Point, to: Point, rx: f32, ry: f32, angle: f32, large_arc: bool, sweep: bool) {
+    extend(bounds, matrix.apply(from));
+    extend(bounds, matrix.apply(to));
+
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+
+    // Degenerated arcs are either omitted or straight lines
+    if from == to || rx == 0.0 || ry == 0.0 {
+        return;
+    }
+
+    let (sin, cos) = angle.to_radians().sin_cos();
+
+    let half = (from - to) * 0.5;
+    let p1 = Point(cos * half.0 + sin * half.1, -sin * half.0 + cos * half.1);
+
+    // Scale up radii which are too small to reach the end point
+    let lambda = (p1.0 * p1.0) / (rx * rx) + (p1.1 * p1.1) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let num = rx * rx * ry * ry - rx * rx * p1.1 * p1.1 - ry * ry * p1.0 * p1.0;
+    let den = rx * rx * p1.1 * p1.1 + ry * ry * p1.0 * p1.0;
+
+    let sign = if large_arc != sweep { 1.0 } else { -1.0 };
+    let coef = sign * (num / den).max(0.0).sqrt();
+
+    let c1 = Point(coef * rx * p1.1 / ry, -coef * ry * p1.0 / rx);
+
+    let mid = (from + to) * 0.5;
+    let center = Point(cos * c1.0 - sin * c1.1 + mid.0, sin * c1.0 + cos * c1.1 + mid.1);
+
+    let start = vector_angle(Point(1.0, 0.0), Point((p1.0 - c1.0) / rx, (p1.1 - c1.1) / ry));
+    let mut delta = vector_angle(
+        Point((p1.0 - c1.0) / rx, (p1.1 - c1.1) / ry),
+        Point((-p1.0 - c1.0) / rx, (-p1.1 - c1.1) / ry),
+    );
+
+    if !sweep && delta > 0.0 {
+        delta -= TAU;
+    } else if sweep && delta < 0.0 {
+        delta += TAU;
+    }
+
+    // Transformed ellipse: c + u * cos(t) + v * sin(t)
+    let c = matrix.apply(center);
+    let u = matrix.apply_vector(Point(rx * cos, rx * sin));
+    let v = matrix.apply_vector(Point(-ry * sin, ry * cos));
+
+    let on_arc = |t: f32| {
+        if delta >= 0.0 {
+            (t - start).rem_euclid(TAU) <= delta
+        } else {
+            (start - t).rem_euclid(TAU) <= -delta
+        }
+    };
+
+    for base in [v.0.atan2(u.0), v.1.atan2(u.1)] {
+        for t in [base, base + PI] {
+            if on_arc(t) {
+                extend(bounds, c + u * t.cos() + v * t.sin());
+            }
+        }
+    }
+}
+
+/// Bounding box of a polygon or polyline given by its `points` value.
+pub(crate) fn points_bbox(points: &str, matrix: &Matrix) -> Option<BoundingBox> {
+    let coords = points.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|item| !item.is_empty())
+        .map(|item| item.parse::<f32>().ok())
+        .collect::<Option<Vec<f32>>>()?;
+
+    BoundingBox::from_points(coords.chunks_exact(2).map(|pair| matrix.apply(Point(pair[0], pair[1]))))
+}
+
+/// Bounding box of path data.
+pub(crate) fn path_bbox(data: &PathData, matrix: &Matrix) -> Option<BoundingBox> {
+    let mut bounds = None;
+    let mut cursor = PathCursor::new();
+
+    let m = |p: Point| matrix.apply(p);
+
+    for cmd in data.commands() {
+        let from = cursor.current();
+        let subpath_start = cursor.subpath_start();
+        let cubic_control = cursor.reflected_cubic_control();
+        let quadratic_control = cursor.reflected_quadratic_control();
+
+        match cursor.advance(cmd) {
+            PathCommandKind::MoveToAbs(_) => {}
+            PathCommandKind::LineAbs(_) |
+                PathCommandKind::HorizontalAbs(_) |
+                PathCommandKind::VerticalAbs(_) =>
+            {
+                extend(&mut bounds, m(from));
+                extend(&mut bounds, m(cursor.current()));
+            }
+            PathCommandKind::CubicBezierAbs { con_start, con_end, to } => {
+                extend_cubic(&mut bounds, [m(from), m(con_start), m(con_end), m(to)]);
+            }
+            PathCommandKind::SmoothCubicBezierAbs { con_end, to } => {
+                extend_cubic(&mut bounds, [m(from), m(cubic_control), m(con_end), m(to)]);
+            }
+            PathCommandKind::QuadraticBezierAbs { con, to } => {
+                extend_quadratic(&mut bounds, [m(from), m(con), m(to)]);
+            }
+            PathCommandKind::SmoothQuadraticBezierAbs(to) => {
+                extend_quadratic(&mut bounds, [m(from), m(quadratic_control), m(to)]);
+            }
+            PathCommandKind::EllipticalArcAbs { radius_x, radius_y, angle, laf, sf, shift } => {
+                extend_arc(
+                    &mut bounds, matrix,
+                    from, shift,
+                    radius_x, radius_y, angle,
+                    laf as u8 == 1, sf == SweepFlag::Clockwise,
+                );
+            }
+            PathCommandKind::Close => {
+                extend(&mut bounds, m(from));
+                extend(&mut bounds, m(subpath_start));
+            }
+            // `advance` only yields absolute commands
+            _ => {}
+        }
+    }
+
+    bounds
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attr::view_box::ViewBox;
+    use crate::element::{Children, Circle, Ellipse, Group, Line, Path, Polyline, Rect, Svg};
+
+    fn assert_bbox(bbox: Option<BoundingBox>, expected: (f32, f32, f32, f32)) {
+        let bbox = bbox.unwrap();
+        let actual = (bbox.x(), bbox.y(), bbox.width(), bbox.height());
+
+        assert!(
+            (actual.0 - expected.0).abs() < 1e-3 &&
+                (actual.1 - expected.1).abs() < 1e-3 &&
+                (actual.2 - expected.2).abs() < 1e-3 &&
+                (actual.3 - expected.3).abs() < 1e-3,
+            "{actual:?} != {expected:?}",
+        );
+    }
+
+    #[test]
+    fn curve_extrema() {
+        let path = Path::from_data("M 0 0 C 0 10 10 10 10 0 Q 15 -10 20 0".parse().unwrap());
+        assert_bbox(path.bbox(), (0.0, -5.0, 20.0, 12.5));
+    }
+
+    #[test]
+    fn arc_extrema() {
+        let path = Path::from_data("M 0 0 A 10 10 0 0 0 20 0".parse().unwrap());
+        assert_bbox(path.bbox(), (0.0, 0.0, 20.0, 10.0));
+
+        let path = Path::from_data("M 0 0 A 10 5 90 1 1 0 20".parse().unwrap());
+        assert_bbox(path.bbox(), (0.0, 0.0, 5.0, 20.0));
+    }
+
+    #[test]
+    fn transformed_elements() {
+        let mut rect = Rect::new(0, 0, 10, 20);
+        rect.set_attr(Attr::from("transform"), "translate(5 5) rotate(90)");
+
+        let mut group = Group::new();
+        group.set_attr(Attr::from("transform"), "scale(2)");
+
+        group.children_mut().unwrap().push(ChildKind::Element(rect.into()));
+        group.children_mut().unwrap().push(ChildKind::Element(Circle::new((30, 0), 5).into()));
+
+        assert_bbox(group.bbox(), (-30.0, -10.0, 100.0, 40.0));
+
+        let mut svg = Svg::new();
+        svg.children_mut().unwrap().push(ChildKind::Element(group.into()));
+        svg.fit_view_box();
+
+        assert_eq!(svg.view_box(), Some(ViewBox::new(-30.0, -10.0, 100.0, 40.0)));
+    }
+
+    #[test]
+    fn basic_shapes() {
+        assert_bbox(Ellipse::new((10, 10), (5, 2)).bbox(), (5.0, 8.0, 10.0, 4.0));
+        assert_bbox(Line::new((4, 0), (0, 3)).bbox(), (0.0, 0.0, 4.0, 3.0));
+        assert_bbox(Polyline::new(&"0,0 10,5 -2 3").bbox(), (-2.0, 0.0, 12.0, 5.0));
+    }
+}
@@ -1,2 +1,8 @@
 /// XML namespace for SVG
 pub const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// Public identifier of the SVG 1.1 document type
+pub const SVG_1_1_PUBLIC_ID: &str = "-//W3C//DTD SVG 1.1//EN";
+
+/// System identifier of the SVG 1.1 document type
+pub const SVG_1_1_SYSTEM_ID: &str = "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd";
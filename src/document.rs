@@ -0,0 +1,241 @@
+use std::{io::Write, str::FromStr};
+
+use quick_xml::{events::{BytesDecl, BytesText, Event}, Writer};
+
+use crate::{constants::{SVG_1_1_PUBLIC_ID, SVG_1_1_SYSTEM_ID}, element::{impl_to_string, Svg, WriteXml}, error::Error, serialize::SerializeOptions};
+
+/// Comment or processing instruction before the root element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrologItem {
+    /// `<!-- ... -->`
+    Comment(String),
+    /// `<?target data?>`, e.g. `<?xml-stylesheet href="style.css"?>`
+    ProcessingInstruction {
+        target: String,
+        data: String,
+    },
+}
+
+/// Standalone SVG document wrapping the root `<svg>` element
+///
+/// The prolog is written in the following order: the XML declaration,
+/// the document type declaration, then comments and processing
+/// instructions as pushed. Each of them is put on its own line.
+pub struct Document {
+    declaration: bool,
+    doctype: bool,
+    prolog: Vec<PrologItem>,
+    root: Svg,
+}
+
+impl Document {
+    /// Create a document with the XML declaration and without DOCTYPE.
+    pub fn new(root: Svg) -> Self {
+        Self {
+            declaration: true,
+            doctype: false,
+            prolog: Vec::new(),
+            root,
+        }
+    }
+
+    #[inline]
+    pub fn root(&self) -> &Svg {
+        &self.root
+    }
+
+    #[inline]
+    pub fn root_mut(&mut self) -> &mut Svg {
+        &mut self.root
+    }
+
+    #[inline]
+    pub fn into_root(self) -> Svg {
+        self.root
+    }
+
+    /// Whether `<?xml version="1.0" encoding="UTF-8" standalone="no"?>` is written.
+    #[inline]
+    pub fn declaration(&self) -> bool {
+        self.declaration
+    }
+
+    /// Set whether the XML declaration is written.
+    #[inline]
+    pub fn set_declaration(&mut self, declaration: bool) -> &mut Self {
+        self.declaration = declaration;
+        self
+    }
+
+    /// Whether the SVG 1.1 document type declaration is written.
+    #[inline]
+    pub fn doctype(&self) -> bool {
+        self.doctype
+    }
+
+    /// Set whether the SVG 1.1 document type declaration is written.
+    #[inline]
+    pub fn set_doctype(&mut self, doctype: bool) -> &mut Self {
+        self.doctype = doctype;
+        self
+    }
+
+    /// Access comments and processing instructions before the root element.
+    #[inline]
+    pub fn prolog(&self) -> &Vec<PrologItem> {
+        &self.prolog
+    }
+
+    /// Access mutable comments and processing instructions before the root element.
+    #[inline]
+    pub fn prolog_mut(&mut self) -> &mut Vec<PrologItem> {
+        &mut self.prolog
+    }
+
+    /// Append a comment before the root element.
+    ///
+    /// The content is written as is, so it must not contain `--`
+    /// nor end with `-`.
+    pub fn push_comment(&mut self, content: &str) -> Result<&mut Self, Error> {
+        if content.contains("--") || content.ends_with('-') {
+            return Err(Error::InvalidComment);
+        }
+
+        self.prolog.push(PrologItem::Comment(content.to_string()));
+        Ok(self)
+    }
+
+    /// Append a processing instruction before the root element.
+    ///
+    /// The target must be a name other than `xml`, and the data,
+    /// written as is, must not contain `?>`.
+    pub fn push_processing_instruction(&mut self, target: &str, data: &str) -> Result<&mut Self, Error> {
+        let valid_target = !target.is_empty()
+            && !target.eq_ignore_ascii_case("xml")
+            && !target.contains(|c: char| c.is_ascii_whitespace() || matches!(c, '?' | '<' | '>'));
+
+        if !valid_target || data.contains("?>") {
+            return Err(Error::InvalidProcessingInstruction);
+        }
+
+        self.prolog.push(PrologItem::ProcessingInstruction {
+            target: target.to_string(),
+            data: data.to_string(),
+        });
+        Ok(self)
+    }
+
+    /// Append an `xml-stylesheet` processing instruction linking a CSS file.
+    pub fn push_stylesheet(&mut self, href: &str) -> &mut Self {
+        // Escaping `>` keeps `?>` out of the data
+        let href = quick_xml::escape::escape(href);
+
+        self.prolog.push(PrologItem::ProcessingInstruction {
+            target: "xml-stylesheet".to_string(),
+            data: format!(r#"type="text/css" href="{href}""#),
+        });
+        self
+    }
+
+    fn write_line<W: Write>(writer: &mut Writer<W>, event: Event) -> Result<(), Error> {
+        writer.write_event(event).map_err(Error::XmlWriterError)?;
+        writer.get_mut().write_all(b"\n").map_err(Error::IoError)
+    }
+}
+
+impl From<Svg> for Document {
+    #[inline]
+    fn from(value: Svg) -> Self {
+        Self::new(value)
+    }
+}
+
+impl WriteXml for Document {
+    fn write_xml<W: Write>(&self, writer: &mut Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), Error> {
+        if self.declaration {
+            Self::write_line(writer, Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), Some("no"))))?;
+        }
+
+        if self.doctype {
+            let doctype = format!(r#"svg PUBLIC "{SVG_1_1_PUBLIC_ID}" "{SVG_1_1_SYSTEM_ID}""#);
+            Self::write_line(writer, Event::DocType(BytesText::from_escaped(doctype)))?;
+        }
+
+        for item in self.prolog.iter() {
+            let event = match item {
+                PrologItem::Comment(content) => Event::Comment(BytesText::from_escaped(content.as_str())),
+                PrologItem::ProcessingInstruction { target, data } if data.is_empty() => Event::PI(BytesText::from_escaped(target.as_str())),
+                PrologItem::ProcessingInstruction { target, data } => Event::PI(BytesText::from_escaped(format!("{target} {data}"))),
+            };
+
+            Self::write_line(writer, event)?;
+        }
+
+        self.root.write_xml(writer, options, depth)
+    }
+}
+
+impl_to_string!(Document);
+
+impl FromStr for Document {
+    type Err = Error;
+
+    /// Parse an SVG document, keeping its prolog.
+    ///
+    /// Any document type declaration is read as the SVG 1.1 one.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parser::parse_document(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn write_prolog() {
+        let mut doc = Document::new(Svg::new());
+
+        doc.set_doctype(true)
+            .push_comment(" Generated ").unwrap()
+            .push_stylesheet("a.css?x=1&y=2");
+
+        let expected = concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?>\n",
+            "<!DOCTYPE svg PUBLIC \"-//W3C//DTD SVG 1.1//EN\" \"http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd\">\n",
+            "<!-- Generated -->\n",
+            "<?xml-stylesheet type=\"text/css\" href=\"a.css?x=1&amp;y=2\"?>\n",
+            "<svg xmlns=\"http://www.w3.org/2000/svg\"/>",
+        );
+
+        assert_eq!(doc.to_string(), expected);
+
+        let parsed: Document = expected.parse().unwrap();
+        assert!(parsed.declaration() && parsed.doctype());
+        assert_eq!(parsed.prolog(), doc.prolog());
+        assert_eq!(parsed.to_string(), expected);
+
+        doc.set_declaration(false).set_doctype(false).prolog_mut().clear();
+        assert_eq!(doc.to_string(), r#"<svg xmlns="http://www.w3.org/2000/svg"/>"#);
+    }
+
+    #[test]
+    fn reject_malformed_prolog() {
+        let mut doc = Document::new(Svg::new());
+
+        assert!(matches!(doc.push_comment("a -- b"), Err(Error::InvalidComment)));
+        assert!(matches!(doc.push_comment("a -"), Err(Error::InvalidComment)));
+        assert!(matches!(doc.push_processing_instruction("", "a"), Err(Error::InvalidProcessingInstruction)));
+        assert!(matches!(doc.push_processing_instruction("XML", "a"), Err(Error::InvalidProcessingInstruction)));
+        assert!(matches!(doc.push_processing_instruction("a b", "c"), Err(Error::InvalidProcessingInstruction)));
+        assert!(matches!(doc.push_processing_instruction("a", "b ?> c"), Err(Error::InvalidProcessingInstruction)));
+        assert!(doc.prolog().is_empty());
+
+        doc.set_declaration(false)
+            .push_comment("a - b").unwrap()
+            .push_processing_instruction("xml-model", "href=\"a.rng\"").unwrap();
+
+        assert_eq!(doc.to_string(), "<!--a - b-->\n<?xml-model href=\"a.rng\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\"/>");
+    }
+}
@@ -1,8 +1,8 @@
-use std::io::Cursor;
+use std::{fs::File, io::{BufWriter, Write}};
 
 use quick_xml::{events::{BytesEnd, BytesStart, BytesText, Event}, Writer};
 
-use crate::error::Error;
+use crate::{error::Error, serialize::SerializeOptions};
 
 macro_rules! def_element_kind {
     ($($type_name:tt),*) => {
@@ -11,10 +11,10 @@ macro_rules! def_element_kind {
         }
 
         impl WriteXml for ElementKind {
-            fn write_xml(&self, writer: &mut Writer<Cursor<Vec<u8>>>) -> Result<(), Error> {
+            fn write_xml<W: Write>(&self, writer: &mut Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), Error> {
                 Ok(match self {
                     $(
-                        ElementKind::$type_name(inner) => inner.write_xml(writer)?,
+                        ElementKind::$type_name(inner) => inner.write_xml(writer, options, depth)?,
                     )*
                 })
             }
@@ -38,6 +38,85 @@ macro_rules! def_element_kind {
             }
 
         }
+
+        impl crate::attr::AccessAttr for ElementKind {
+            fn attr(&self, attr: &crate::attr::Attr) -> Option<&str> {
+                match self {
+                    $(
+                        ElementKind::$type_name(inner) => inner.attr(attr),
+                    )*
+                }
+            }
+
+            fn set_attr(&mut self, attr: crate::attr::Attr, value: &str) {
+                match self {
+                    $(
+                        ElementKind::$type_name(inner) => inner.set_attr(attr, value),
+                    )*
+                }
+            }
+
+            fn pop_attr(&mut self, attr: &crate::attr::Attr) -> Option<String> {
+                match self {
+                    $(
+                        ElementKind::$type_name(inner) => inner.pop_attr(attr),
+                    )*
+                }
+            }
+        }
+
+        impl crate::bbox::BBox for ElementKind {
+            fn transformed_bbox(&self, matrix: &crate::attr::transform::Matrix) -> Option<crate::bbox::BoundingBox> {
+                match self {
+                    $(
+                        ElementKind::$type_name(inner) => inner.transformed_bbox(matrix),
+                    )*
+                }
+            }
+        }
+
+        impl ReadXml for ElementKind {
+            fn read_attr(&mut self, key: &str, value: &str) -> Result<(), Error> {
+                match self {
+                    $(
+                        ElementKind::$type_name(inner) => inner.read_attr(key, value),
+                    )*
+                }
+            }
+        }
+
+        impl ElementKind {
+            /// Instantiate an empty element by its tag name.
+            ///
+            /// Returns `None` if the tag is not supported.
+            pub fn from_tag_name(tag: &str) -> Option<Self> {
+                $(
+                    if tag == <$type_name as TagName>::tag_name() {
+                        return Some(ElementKind::$type_name($type_name::default()));
+                    }
+                )*
+
+                None
+            }
+
+            /// Access the tag name of the inner element.
+            pub fn tag(&self) -> &'static str {
+                match self {
+                    $(
+                        ElementKind::$type_name(_) => <$type_name as TagName>::tag_name(),
+                    )*
+                }
+            }
+        }
+
+        $(
+            impl From<$type_name> for ElementKind {
+                #[inline]
+                fn from(value: $type_name) -> Self {
+                    ElementKind::$type_name(value)
+                }
+            }
+        )*
     };
 }
 
@@ -50,13 +129,56 @@ macro_rules! include_elements {
 }
 
 include_elements!(
+    a, A,
+    animate, Animate,
+    animate_motion, AnimateMotion,
+    animate_transform, AnimateTransform,
     circle, Circle,
+    clip_path, ClipPath,
+    defs, Defs,
+    desc, Desc,
+    ellipse, Ellipse,
+    fe_blend, FeBlend,
+    fe_color_matrix, FeColorMatrix,
+    fe_composite, FeComposite,
+    fe_diffuse_lighting, FeDiffuseLighting,
+    fe_displacement_map, FeDisplacementMap,
+    fe_distant_light, FeDistantLight,
+    fe_drop_shadow, FeDropShadow,
+    fe_flood, FeFlood,
+    fe_gaussian_blur, FeGaussianBlur,
+    fe_merge, FeMerge,
+    fe_merge_node, FeMergeNode,
+    fe_morphology, FeMorphology,
+    fe_offset, FeOffset,
+    fe_point_light, FePointLight,
+    fe_specular_lighting, FeSpecularLighting,
+    fe_spot_light, FeSpotLight,
+    fe_turbulence, FeTurbulence,
+    filter, Filter,
+    foreign_object, ForeignObject,
     group, Group,
+    image, Image,
+    line, Line,
+    linear_gradient, LinearGradient,
+    marker, Marker,
+    mask, Mask,
+    metadata, Metadata,
+    mpath, MPath,
     path, Path,
     polygon, Polygon,
+    polyline, Polyline,
+    radial_gradient, RadialGradient,
     svg, Svg,
+    symbol, Symbol,
     rect, Rect,
-    text, Text
+    set, Set,
+    stop, Stop,
+    text, Text,
+    text_path, TextPath,
+    title, Title,
+    tspan, TSpan,
+    r#use, Use
 );
 
 /// Instance having a tag name.
@@ -76,12 +198,52 @@ pub trait Children {
 
 /// Instance can be written as XML through the `quick_xml` writer.
 pub trait WriteXml {
-    /// Convert current instance into XML by `quick_xml`'s writer.
-    fn write_xml(&self, writer: &mut Writer<Cursor<Vec<u8>>>) -> Result<(), Error>;
+    /// Convert current instance into XML by `quick_xml`'s writer,
+    /// with `depth` being the nesting level used for indentation.
+    fn write_xml<W: Write>(&self, writer: &mut Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), Error>;
+
+    /// Stream current instance as compact XML into any sink, e.g. a socket
+    /// or a compressed writer.
+    ///
+    /// The sink is not flushed.
+    #[inline]
+    fn write_to<W: Write>(&self, sink: &mut W) -> Result<(), Error> {
+        self.write_to_with(sink, &SerializeOptions::default())
+    }
+
+    /// Stream current instance as XML into any sink, formatted by the options.
+    ///
+    /// The sink is not flushed.
+    fn write_to_with<W: Write>(&self, sink: &mut W, options: &SerializeOptions) -> Result<(), Error> {
+        self.write_xml(&mut Writer::new(sink), options, 0)
+    }
+
+    /// Write current instance as compact XML into a file, which is created
+    /// or truncated.
+    #[inline]
+    fn write_to_file<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), Error> {
+        self.write_to_file_with(path, &SerializeOptions::default())
+    }
+
+    /// Write current instance as XML into a file formatted by the options,
+    /// which is created or truncated.
+    fn write_to_file_with<P: AsRef<std::path::Path>>(&self, path: P, options: &SerializeOptions) -> Result<(), Error> {
+        let mut file = BufWriter::new(File::create(path).map_err(Error::IoError)?);
+
+        self.write_to_with(&mut file, options)?;
+
+        file.flush().map_err(Error::IoError)
+    }
+}
+
+/// Instance can be built from XML read by `quick_xml`'s reader.
+pub trait ReadXml {
+    /// Assign an attribute read from XML to current instance.
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), Error>;
 }
 
 /// Element node trait.
-pub trait ElementNode: TagName + WriteXml + ToString + TryToString + Children {}
+pub trait ElementNode: TagName + WriteXml + ReadXml + ToString + TryToString + Children {}
 
 /// Internal helper macro for implementing tag name trait.
 macro_rules! impl_tag {
@@ -140,26 +302,30 @@ pub type LazyChildList = Option<ChildList>;
 /// Instances that might be converted to string.
 pub trait TryToString {
     /// Try to convert current instance to string.
-    fn try_to_string(&self) -> Result<String, crate::error::Error>;
+    fn try_to_string(&self) -> Result<String, crate::error::Error> {
+        self.try_to_string_with(&SerializeOptions::default())
+    }
+
+    /// Try to convert current instance to string formatted by the options.
+    fn try_to_string_with(&self, options: &SerializeOptions) -> Result<String, crate::error::Error>;
 }
 
 macro_rules! impl_to_string {
     ($struct_name:tt) => {
         impl crate::element::TryToString for $struct_name {
-            fn try_to_string(&self) -> Result<String, crate::error::Error> {
-                let mut writer = quick_xml::Writer::new(std::io::Cursor::new(Vec::new()));
-                self.write_xml(&mut writer)?;
+            fn try_to_string_with(&self, options: &crate::serialize::SerializeOptions) -> Result<String, crate::error::Error> {
+                let mut out = Vec::new();
+                crate::element::WriteXml::write_to_with(self, &mut out, options)?;
 
-                let out = writer.into_inner().into_inner();
                 Ok(std::str::from_utf8(&out)
-                   .or_else(|err| Err(crate::error::Error::Utf8ParseError(err)))?
+                   .map_err(crate::error::Error::Utf8ParseError)?
                    .to_string())
             }
         }
 
-        impl ToString for $struct_name {
-            fn to_string(&self) -> String {
-                crate::element::TryToString::try_to_string(self).unwrap()
+        impl std::fmt::Display for $struct_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&crate::element::TryToString::try_to_string(self).map_err(|_| std::fmt::Error)?)
             }
         }
     };
@@ -167,34 +333,58 @@ macro_rules! impl_to_string {
 
 pub(crate) use impl_to_string;
 
-pub(crate) fn convert_into_xml(writer: &mut Writer<Cursor<Vec<u8>>>, bs: BytesStart, children: Option<&ChildList>, tag: &str) -> Result<(), Error> {
-    if let Some(children) = children {
-        writer.write_event(Event::Start(bs))
-            .or_else(|err| Err(Error::XmlWriterError(err)))?;
+pub(crate) fn convert_into_xml<W: Write>(writer: &mut Writer<W>, mut bs: BytesStart, children: Option<&ChildList>, tag: &str, options: &SerializeOptions, depth: usize) -> Result<(), Error> {
+    options.attr_order.sort(&mut bs)?;
 
-        for child in children.iter() {
-            match child {
-                ChildKind::String(ref content) => {
-                    writer.write_event(Event::Text(BytesText::new(content.as_str())))
-                        .or_else(|err| Err(Error::XmlWriterError(err)))?;
-                }
-                ChildKind::Element(ref el) => {
-                    el.write_xml(writer)?;
-                }
+    let Some(children) = children else {
+        return writer.write_event(Event::Empty(bs))
+            .map_err(Error::XmlWriterError);
+    };
+
+    writer.write_event(Event::Start(bs))
+        .map_err(Error::XmlWriterError)?;
+
+    // Line breaks would alter the text, so text content and mixed content
+    // stay inline along with all their descendants
+    let inline = !options.newlines
+        || children.is_empty()
+        || matches!(tag, "text" | "tspan" | "textPath")
+        || options.inline_text && children.iter().any(|child| matches!(child, ChildKind::String(_)));
+
+    let inline_options;
+    let child_options = if inline && options.newlines {
+        inline_options = SerializeOptions { newlines: false, ..options.clone() };
+        &inline_options
+    } else {
+        options
+    };
+
+    for child in children.iter() {
+        if !inline {
+            options.write_break(writer, depth + 1)?;
+        }
+
+        match child {
+            ChildKind::String(ref content) => {
+                writer.write_event(Event::Text(BytesText::new(content.as_str())))
+                    .map_err(Error::XmlWriterError)?;
+            }
+            ChildKind::Element(ref el) => {
+                el.write_xml(writer, child_options, depth + 1)?;
             }
         }
+    }
 
-        writer.write_event(Event::End(BytesEnd::new(tag)))
-            .or_else(|err| Err(Error::XmlWriterError(err)))?;
-    } else {
-        writer.write_event(Event::Empty(bs))
-            .or_else(|err| Err(Error::XmlWriterError(err)))?;
+    if !inline {
+        options.write_break(writer, depth)?;
     }
 
-    Ok(())
+    writer.write_event(Event::End(BytesEnd::new(tag)))
+        .map_err(Error::XmlWriterError)
 }
 
 /// Child kind enumeration
+#[allow(clippy::large_enum_variant)]
 pub enum ChildKind {
     /// Raw string
     String(String),
@@ -214,7 +404,7 @@ macro_rules! impl_element {
     ($name:ident?, $tag:literal) => {
         crate::element::impl_tag!($name, $tag);
         crate::element::impl_children!($name?);
-        crate::element::impl_to_string!(Path);
+        crate::element::impl_to_string!($name);
 
         impl crate::element::ElementNode for $name {}
     };
@@ -291,12 +481,116 @@ macro_rules! impl_accessor {
         #[doc = $attr]
         #[doc = ")."]
 
-        pub fn $setter<T>(&mut self, value: Option<$type>) -> &mut Self {
+        pub fn $setter(&mut self, value: Option<$type>) -> &mut Self {
             self.$name = value;
             self
         }
     };
 
+    (primitive:raw -> $name:ident, $setter:ident, $type:ty, $attr:literal) => {
+        #[doc = "Get `"]
+        #[doc = $attr]
+        #[doc = "`\n\n"]
+        #[doc = "See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/"]
+        #[doc = $attr]
+        #[doc = ")."]
+        pub fn $name(&self) -> Option<$type> {
+            self.$name
+        }
+
+        #[doc = "Set `"]
+        #[doc = $attr]
+        #[doc = "`\n\n"]
+        #[doc = "See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/"]
+        #[doc = $attr]
+        #[doc = ")."]
+        pub fn $setter(&mut self, value: Option<$type>) -> &mut Self {
+            self.$name = value;
+
+            // Drop the raw value kept for a value which could not be parsed
+            crate::attr::AccessAttr::pop_attr(self, &crate::attr::Attr::from($attr));
+            self
+        }
+    };
+
+    (length -> $name:ident, $setter:ident, $attr:literal) => {
+        #[doc = "Get `"]
+        #[doc = $attr]
+        #[doc = "`\n\n"]
+        #[doc = "See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/"]
+        #[doc = $attr]
+        #[doc = ")."]
+        pub fn $name(&self) -> Option<crate::attr::length::Length> {
+            self.$name
+        }
+
+        #[doc = "Set `"]
+        #[doc = $attr]
+        #[doc = "`\n\n"]
+        #[doc = "See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/"]
+        #[doc = $attr]
+        #[doc = ")."]
+        pub fn $setter<T>(&mut self, value: Option<T>) -> &mut Self
+        where
+            T: Into<crate::attr::length::Length>,
+        {
+            self.$name = value.map(Into::into);
+
+            // Drop the raw value kept for a length which could not be parsed
+            crate::attr::AccessAttr::pop_attr(self, &crate::attr::Attr::from($attr));
+            self
+        }
+    };
+
+    (href -> $name:ident, $setter:ident) => {
+        impl_accessor!(string* -> $name, $setter, "href");
+
+        /// Whether `href` is written as `xlink:href`.
+        #[inline]
+        pub fn xlink(&self) -> bool {
+            self.xlink
+        }
+
+        /// Set whether `href` is written as `xlink:href` for SVG 1.1 user agents.
+        ///
+        /// The root element must then declare `xmlns:xlink`.
+        #[inline]
+        pub fn set_xlink(&mut self, xlink: bool) -> &mut Self {
+            self.xlink = xlink;
+            self
+        }
+    };
+
+    (map -> $name:ident, $setter:ident, $type:ty, $attr:literal) => {
+        #[doc = "Get `"]
+        #[doc = $attr]
+        #[doc = "`, or `None` if missing or invalid\n\n"]
+        #[doc = "See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/"]
+        #[doc = $attr]
+        #[doc = ")."]
+        pub fn $name(&self) -> Option<$type> {
+            // Kept in the attribute map, shared with `AccessAttr`
+            crate::attr::AccessAttr::attr(self, &crate::attr::Attr::from($attr))?.parse().ok()
+        }
+
+        #[doc = "Set `"]
+        #[doc = $attr]
+        #[doc = "`\n\n"]
+        #[doc = "See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/"]
+        #[doc = $attr]
+        #[doc = ")."]
+        pub fn $setter(&mut self, value: Option<$type>) -> &mut Self {
+            match value {
+                Some(value) => crate::attr::AccessAttr::set_attr(self, crate::attr::Attr::from($attr), &value.to_string()),
+                None => {
+                    crate::attr::AccessAttr::pop_attr(self, &crate::attr::Attr::from($attr));
+                }
+            }
+
+            self
+        }
+    };
+
     (list:primitive -> $name:ident, $setter:ident, $attr:literal, $type:ty) => {
         #[doc = "Get `"]
         #[doc = $attr]
@@ -317,6 +611,7 @@ macro_rules! impl_accessor {
         pub fn $setter(&mut self) -> &mut Vec<$type>
         {
             if self.$name.is_none() {
+                crate::attr::AccessAttr::pop_attr(self, &crate::attr::Attr::from($attr));
                 self.$name = Some(Vec::new());
             }
 
@@ -0,0 +1,124 @@
+use quick_xml::events::BytesStart;
+
+#[cfg(feature = "crossorigin")]
+use crate::attr::referrer_policy::ReferrerPolicy;
+use crate::{attr::{impl_attr_accessors, transform::Matrix, LazyAttrMap}, bbox::{children_bbox, local_matrix, BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Hyperlink element (`<a>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/a).
+pub struct A {
+    href: Option<String>,
+    xlink: bool,
+    target: Option<String>,
+    download: Option<String>,
+    rel: Option<String>,
+    hreflang: Option<String>,
+    #[cfg(feature = "crossorigin")]
+    referrer_policy: Option<ReferrerPolicy>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for A {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            href: None,
+            xlink: false,
+            target: None,
+            download: None,
+            rel: None,
+            hreflang: None,
+            #[cfg(feature = "crossorigin")]
+            referrer_policy: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl A {
+    #[inline]
+    pub fn new<T>(href: &T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            href: Some(href.to_string()),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(href -> href, set_href);
+    impl_accessor!(string -> target, set_target, "target");
+    impl_accessor!(string -> download, set_download, "download");
+    impl_accessor!(string -> rel, set_rel, "rel");
+    impl_accessor!(string -> hreflang, set_hreflang, "hreflang");
+    #[cfg(feature = "crossorigin")]
+    impl_accessor!(primitive -> referrer_policy, set_referrer_policy, ReferrerPolicy, "referrerpolicy");
+}
+
+impl_element!(A, "a");
+impl_attr_accessors!(A);
+
+impl WriteXml for A {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(href: self, bs);
+        push_attr!(self.target, bs, "target" <- String);
+        push_attr!(self.download, bs, "download" <- String);
+        push_attr!(self.rel, bs, "rel" <- String);
+        push_attr!(self.hreflang, bs, "hreflang" <- String);
+        #[cfg(feature = "crossorigin")]
+        push_attr!(self.referrer_policy, bs, "referrerpolicy" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for A {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "href" | "xlink:href" => pull_attr!(href: self, key, value),
+            "target" => pull_attr!(self.target, value <- String),
+            "download" => pull_attr!(self.download, value <- String),
+            "rel" => pull_attr!(self.rel, value <- String),
+            "hreflang" => pull_attr!(self.hreflang, value <- String),
+            #[cfg(feature = "crossorigin")]
+            "referrerpolicy" => pull_attr!(self.referrer_policy, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for A {
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        children_bbox(self.children(), &local_matrix(self, matrix)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keep_xlink_href() {
+        let expected = r#"<a xlink:href="https://example.com"><a href="/">Home</a></a>"#;
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+
+        let mut a = A::new(&"/");
+        a.set_xlink(true);
+        assert_eq!(a.to_string(), r#"<a xlink:href="/"/>"#);
+    }
+}
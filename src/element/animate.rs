@@ -0,0 +1,255 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{animation::{Accumulate, Additive, AnimationFill, CalcMode, Duration, KeySpline, RepeatCount, Restart, Timing}, impl_attr_accessors, LazyAttrMap}, bbox::impl_no_bbox, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Animation element (`<animate>`)
+///
+/// The target is the parent element, or the one referenced by `href`.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/animate).
+pub struct Animate {
+    href: Option<String>,
+    xlink: bool,
+    attribute_name: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    by: Option<String>,
+    values: Option<Vec<String>>,
+    key_times: Option<Vec<f32>>,
+    key_splines: Option<Vec<KeySpline>>,
+    calc_mode: Option<CalcMode>,
+    additive: Option<Additive>,
+    accumulate: Option<Accumulate>,
+    begin: Option<Vec<Timing>>,
+    dur: Option<Duration>,
+    end: Option<Vec<Timing>>,
+    min: Option<Duration>,
+    max: Option<Duration>,
+    restart: Option<Restart>,
+    repeat_count: Option<RepeatCount>,
+    repeat_dur: Option<Duration>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Animate {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            href: None,
+            xlink: false,
+            attribute_name: None,
+            from: None,
+            to: None,
+            by: None,
+            values: None,
+            key_times: None,
+            key_splines: None,
+            calc_mode: None,
+            additive: None,
+            accumulate: None,
+            begin: None,
+            dur: None,
+            end: None,
+            min: None,
+            max: None,
+            restart: None,
+            repeat_count: None,
+            repeat_dur: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Animate {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Animate `attribute_name` from one value to another during `dur`.
+    pub fn from_to<D>(attribute_name: &str, from: &str, to: &str, dur: D) -> Self
+    where
+        D: Into<Duration>,
+    {
+        Self {
+            attribute_name: Some(attribute_name.to_string()),
+            from: Some(from.to_string()),
+            to: Some(to.to_string()),
+            dur: Some(dur.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Animate `attribute_name` through `values` during `dur`.
+    pub fn with_values<I, T, D>(attribute_name: &str, values: I, dur: D) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: ToString,
+        D: Into<Duration>,
+    {
+        Self {
+            attribute_name: Some(attribute_name.to_string()),
+            values: Some(values.into_iter().map(|value| value.to_string()).collect()),
+            dur: Some(dur.into()),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(href -> href, set_href);
+    impl_accessor!(string -> attribute_name, set_attribute_name, "attributeName");
+    impl_accessor!(string -> from, set_from, "from");
+    impl_accessor!(string -> to, set_to, "to");
+    impl_accessor!(string -> by, set_by, "by");
+    impl_accessor!(list:primitive -> values, values_mut, "values", String);
+    impl_accessor!(list:primitive -> key_times, key_times_mut, "keyTimes", f32);
+    impl_accessor!(list:primitive -> key_splines, key_splines_mut, "keySplines", KeySpline);
+    impl_accessor!(primitive -> calc_mode, set_calc_mode, CalcMode, "calcMode");
+    impl_accessor!(primitive -> additive, set_additive, Additive, "additive");
+    impl_accessor!(primitive -> accumulate, set_accumulate, Accumulate, "accumulate");
+    impl_accessor!(list:primitive -> begin, begin_mut, "begin", Timing);
+    impl_accessor!(primitive -> dur, set_dur, Duration, "dur");
+    impl_accessor!(list:primitive -> end, end_mut, "end", Timing);
+    impl_accessor!(primitive -> min, set_min, Duration, "min");
+    impl_accessor!(primitive -> max, set_max, Duration, "max");
+    impl_accessor!(primitive -> restart, set_restart, Restart, "restart");
+    impl_accessor!(primitive -> repeat_count, set_repeat_count, RepeatCount, "repeatCount");
+    impl_accessor!(primitive -> repeat_dur, set_repeat_dur, Duration, "repeatDur");
+    impl_accessor!(map -> fill, set_fill, AnimationFill, "fill");
+}
+
+impl_element!(Animate, "animate");
+impl_attr_accessors!(Animate);
+
+impl WriteXml for Animate {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(href: self, bs);
+        push_attr!(self.attribute_name, bs, "attributeName" <- String);
+        push_attr!(self.from, bs, "from" <- String);
+        push_attr!(self.to, bs, "to" <- String);
+        push_attr!(self.by, bs, "by" <- String);
+        push_attr!(self.values, bs, "values" <- strings | ";");
+        push_attr!(self.key_times, bs, "keyTimes" <- Formats(options) | ";");
+        push_attr!(self.key_splines, bs, "keySplines" <- primitives | ";");
+        push_attr!(self.calc_mode, bs, "calcMode" <- ToString);
+        push_attr!(self.additive, bs, "additive" <- ToString);
+        push_attr!(self.accumulate, bs, "accumulate" <- ToString);
+        push_attr!(self.begin, bs, "begin" <- primitives | ";");
+        push_attr!(self.dur, bs, "dur" <- ToString);
+        push_attr!(self.end, bs, "end" <- primitives | ";");
+        push_attr!(self.min, bs, "min" <- ToString);
+        push_attr!(self.max, bs, "max" <- ToString);
+        push_attr!(self.restart, bs, "restart" <- ToString);
+        push_attr!(self.repeat_count, bs, "repeatCount" <- ToString);
+        push_attr!(self.repeat_dur, bs, "repeatDur" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Animate {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "href" | "xlink:href" => pull_attr!(href: self, key, value),
+            "attributeName" => pull_attr!(self.attribute_name, value <- String),
+            "from" => pull_attr!(self.from, value <- String),
+            "to" => pull_attr!(self.to, value <- String),
+            "by" => pull_attr!(self.by, value <- String),
+            "values" => pull_attr!(self.values, value <- strings | ';'),
+            "keyTimes" => pull_attr!(self.key_times, value <- primitives | ';'),
+            "keySplines" => pull_attr!(self.key_splines, value <- primitives | ';'),
+            "calcMode" => pull_attr!(self.calc_mode, value <- FromStr),
+            "additive" => pull_attr!(self.additive, value <- FromStr),
+            "accumulate" => pull_attr!(self.accumulate, value <- FromStr),
+            "begin" => pull_attr!(self.begin, value <- primitives | ';'),
+            "dur" => pull_attr!(self.dur, value <- FromStr),
+            "end" => pull_attr!(self.end, value <- primitives | ';'),
+            "min" => pull_attr!(self.min, value <- FromStr),
+            "max" => pull_attr!(self.max, value <- FromStr),
+            "restart" => pull_attr!(self.restart, value <- FromStr),
+            "repeatCount" => pull_attr!(self.repeat_count, value <- FromStr),
+            "repeatDur" => pull_attr!(self.repeat_dur, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl_no_bbox!(animation: Animate);
+
+#[cfg(test)]
+mod test {
+    use crate::{attr::animation::TransformType, element::{AnimateMotion, AnimateTransform, ChildKind, Group, Set}};
+
+    use super::*;
+
+    #[test]
+    fn build_spinner() {
+        let mut spin = AnimateTransform::from_to(TransformType::Rotate, "0 12 12", "360 12 12", 1.0);
+        spin.set_repeat_count(Some(RepeatCount::Indefinite));
+
+        let mut fade = Animate::with_values("opacity", [1.0, 0.2, 1.0], std::time::Duration::from_millis(800));
+        fade.set_calc_mode(Some(CalcMode::Spline))
+            .set_fill(Some(AnimationFill::Freeze));
+        fade.key_times_mut().extend([0.0, 0.5, 1.0]);
+        fade.key_splines_mut().extend([KeySpline::EASE_IN, KeySpline::EASE_OUT]);
+        fade.begin_mut().extend([Timing::Offset(0.5), Timing::after("intro", 0.0)]);
+
+        let mut show = Set::new("visibility", "visible");
+        show.begin_mut().push(Timing::with("spin", 0.0));
+
+        let group = Group::with_children([spin.into(), fade.into(), show.into(), AnimateMotion::along_path("track", 2.0).into()].map(ChildKind::Element).into_iter());
+
+        let expected = concat!(
+            r#"<g><animateTransform attributeName="transform" type="rotate" from="0 12 12" to="360 12 12" dur="1s" repeatCount="indefinite"/>"#,
+            r#"<animate attributeName="opacity" values="1;0.2;1" keyTimes="0;0.5;1" keySplines="0.42 0 1 1;0 0 0.58 1" calcMode="spline" begin="0.5s;intro.end" dur="0.8s" fill="freeze"/>"#,
+            r#"<set attributeName="visibility" to="visible" begin="spin.begin"/>"#,
+            r##"<animateMotion dur="2s"><mpath href="#track"/></animateMotion></g>"##,
+        );
+
+        assert_eq!(group.to_string(), expected);
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+        assert_eq!(crate::parser::parse(r#"<animate begin=" click ; 1s "/>"#).unwrap().to_string(), r#"<animate begin="click;1s"/>"#);
+    }
+
+    #[test]
+    fn share_fill_attribute() {
+        use crate::{attr::{AccessAttr, Attr}, element::ElementKind};
+
+        let mut el: ElementKind = r#"<set attributeName="x" to="1" fill="freeze"/>"#.parse().unwrap();
+
+        assert_eq!(el.attr(&Attr::from("fill")), Some("freeze"));
+
+        el.set_attr(Attr::from("fill"), "remove");
+        assert_eq!(el.to_string(), r#"<set attributeName="x" to="1" fill="remove"/>"#);
+
+        let ElementKind::Set(mut set) = el else {
+            unreachable!()
+        };
+
+        assert_eq!(set.fill(), Some(AnimationFill::Remove));
+        assert_eq!(set.set_fill(None).to_string(), r#"<set attributeName="x" to="1"/>"#);
+    }
+
+    #[test]
+    fn keep_xlink_href() {
+        let src = concat!(
+            r##"<g><animate xlink:href="#a" attributeName="x" to="1"/><set xlink:href="#a" attributeName="x" to="2"/>"##,
+            r##"<animateTransform xlink:href="#a" attributeName="transform" type="scale" to="2"/>"##,
+            r##"<animateMotion xlink:href="#a"><mpath xlink:href="#track"/></animateMotion><animate href="#a" attributeName="y" to="1"/></g>"##,
+        );
+
+        assert_eq!(crate::parser::parse(src).unwrap().to_string(), src);
+    }
+}
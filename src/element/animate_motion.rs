@@ -0,0 +1,194 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{animation::{Accumulate, Additive, AnimationFill, CalcMode, Duration, KeySpline, MotionRotate, RepeatCount, Restart, Timing}, impl_attr_accessors, LazyAttrMap}, bbox::impl_no_bbox, element::{convert_into_xml, path::PathData, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, ChildKind, MPath, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Motion animation element (`<animateMotion>`)
+///
+/// The target is the parent element, or the one referenced by `href`.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/animateMotion).
+pub struct AnimateMotion {
+    href: Option<String>,
+    xlink: bool,
+    path: Option<PathData>,
+    key_points: Option<Vec<f32>>,
+    rotate: Option<MotionRotate>,
+    from: Option<String>,
+    to: Option<String>,
+    by: Option<String>,
+    values: Option<Vec<String>>,
+    key_times: Option<Vec<f32>>,
+    key_splines: Option<Vec<KeySpline>>,
+    calc_mode: Option<CalcMode>,
+    additive: Option<Additive>,
+    accumulate: Option<Accumulate>,
+    begin: Option<Vec<Timing>>,
+    dur: Option<Duration>,
+    end: Option<Vec<Timing>>,
+    min: Option<Duration>,
+    max: Option<Duration>,
+    restart: Option<Restart>,
+    repeat_count: Option<RepeatCount>,
+    repeat_dur: Option<Duration>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for AnimateMotion {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            href: None,
+            xlink: false,
+            path: None,
+            key_points: None,
+            rotate: None,
+            from: None,
+            to: None,
+            by: None,
+            values: None,
+            key_times: None,
+            key_splines: None,
+            calc_mode: None,
+            additive: None,
+            accumulate: None,
+            begin: None,
+            dur: None,
+            end: None,
+            min: None,
+            max: None,
+            restart: None,
+            repeat_count: None,
+            repeat_dur: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl AnimateMotion {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move along path data during `dur`.
+    pub fn along<D>(path: PathData, dur: D) -> Self
+    where
+        D: Into<Duration>,
+    {
+        Self {
+            path: Some(path),
+            dur: Some(dur.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Move along the `<path>` with the given `id` during `dur`.
+    pub fn along_path<D>(id: &str, dur: D) -> Self
+    where
+        D: Into<Duration>,
+    {
+        Self {
+            dur: Some(dur.into()),
+            children: Some(vec![ChildKind::Element(MPath::referencing(id).into())]),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(href -> href, set_href);
+    impl_accessor!(ref:move_setter -> path, set_path, "path", PathData);
+    impl_accessor!(list:primitive -> key_points, key_points_mut, "keyPoints", f32);
+    impl_accessor!(primitive -> rotate, set_rotate, MotionRotate, "rotate");
+    impl_accessor!(string -> from, set_from, "from");
+    impl_accessor!(string -> to, set_to, "to");
+    impl_accessor!(string -> by, set_by, "by");
+    impl_accessor!(list:primitive -> values, values_mut, "values", String);
+    impl_accessor!(list:primitive -> key_times, key_times_mut, "keyTimes", f32);
+    impl_accessor!(list:primitive -> key_splines, key_splines_mut, "keySplines", KeySpline);
+    impl_accessor!(primitive -> calc_mode, set_calc_mode, CalcMode, "calcMode");
+    impl_accessor!(primitive -> additive, set_additive, Additive, "additive");
+    impl_accessor!(primitive -> accumulate, set_accumulate, Accumulate, "accumulate");
+    impl_accessor!(list:primitive -> begin, begin_mut, "begin", Timing);
+    impl_accessor!(primitive -> dur, set_dur, Duration, "dur");
+    impl_accessor!(list:primitive -> end, end_mut, "end", Timing);
+    impl_accessor!(primitive -> min, set_min, Duration, "min");
+    impl_accessor!(primitive -> max, set_max, Duration, "max");
+    impl_accessor!(primitive -> restart, set_restart, Restart, "restart");
+    impl_accessor!(primitive -> repeat_count, set_repeat_count, RepeatCount, "repeatCount");
+    impl_accessor!(primitive -> repeat_dur, set_repeat_dur, Duration, "repeatDur");
+    impl_accessor!(map -> fill, set_fill, AnimationFill, "fill");
+}
+
+impl_element!(AnimateMotion, "animateMotion");
+impl_attr_accessors!(AnimateMotion);
+
+impl WriteXml for AnimateMotion {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(href: self, bs);
+        push_attr!(self.path, bs, "path" <- Format(options));
+        push_attr!(self.key_points, bs, "keyPoints" <- Formats(options) | ";");
+        push_attr!(self.rotate, bs, "rotate" <- ToString);
+        push_attr!(self.from, bs, "from" <- String);
+        push_attr!(self.to, bs, "to" <- String);
+        push_attr!(self.by, bs, "by" <- String);
+        push_attr!(self.values, bs, "values" <- strings | ";");
+        push_attr!(self.key_times, bs, "keyTimes" <- Formats(options) | ";");
+        push_attr!(self.key_splines, bs, "keySplines" <- primitives | ";");
+        push_attr!(self.calc_mode, bs, "calcMode" <- ToString);
+        push_attr!(self.additive, bs, "additive" <- ToString);
+        push_attr!(self.accumulate, bs, "accumulate" <- ToString);
+        push_attr!(self.begin, bs, "begin" <- primitives | ";");
+        push_attr!(self.dur, bs, "dur" <- ToString);
+        push_attr!(self.end, bs, "end" <- primitives | ";");
+        push_attr!(self.min, bs, "min" <- ToString);
+        push_attr!(self.max, bs, "max" <- ToString);
+        push_attr!(self.restart, bs, "restart" <- ToString);
+        push_attr!(self.repeat_count, bs, "repeatCount" <- ToString);
+        push_attr!(self.repeat_dur, bs, "repeatDur" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for AnimateMotion {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "href" | "xlink:href" => pull_attr!(href: self, key, value),
+            "path" => pull_attr!(self.path, value <- FromStr),
+            "keyPoints" => pull_attr!(self.key_points, value <- primitives | ';'),
+            "rotate" => pull_attr!(self.rotate, value <- FromStr),
+            "from" => pull_attr!(self.from, value <- String),
+            "to" => pull_attr!(self.to, value <- String),
+            "by" => pull_attr!(self.by, value <- String),
+            "values" => pull_attr!(self.values, value <- strings | ';'),
+            "keyTimes" => pull_attr!(self.key_times, value <- primitives | ';'),
+            "keySplines" => pull_attr!(self.key_splines, value <- primitives | ';'),
+            "calcMode" => pull_attr!(self.calc_mode, value <- FromStr),
+            "additive" => pull_attr!(self.additive, value <- FromStr),
+            "accumulate" => pull_attr!(self.accumulate, value <- FromStr),
+            "begin" => pull_attr!(self.begin, value <- primitives | ';'),
+            "dur" => pull_attr!(self.dur, value <- FromStr),
+            "end" => pull_attr!(self.end, value <- primitives | ';'),
+            "min" => pull_attr!(self.min, value <- FromStr),
+            "max" => pull_attr!(self.max, value <- FromStr),
+            "restart" => pull_attr!(self.restart, value <- FromStr),
+            "repeatCount" => pull_attr!(self.repeat_count, value <- FromStr),
+            "repeatDur" => pull_attr!(self.repeat_dur, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl_no_bbox!(animation: AnimateMotion);
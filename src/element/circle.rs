@@ -1,16 +1,16 @@
 use quick_xml::events::BytesStart;
 
-use crate::{attr::{impl_attr_accessors, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr};
+use crate::{attr::{impl_attr_accessors, length::Length, transform::Matrix, LazyAttrMap}, bbox::{ellipse_bbox, local_matrix, BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions, Point};
 
-use super::{impl_accessor, impl_element, ChildList, TagName, WriteXml};
+use super::{impl_accessor, impl_element, ChildList, ReadXml, TagName, WriteXml};
 
 /// Circle element (`<circle>`)
 ///
 /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/circle).
 pub struct Circle {
-    cx: Option<String>,
-    cy: Option<String>,
-    radius: Option<String>,
+    cx: Option<Length>,
+    cy: Option<Length>,
+    radius: Option<Length>,
     
     attr: LazyAttrMap,
 
@@ -19,7 +19,6 @@ pub struct Circle {
 
 impl Default for Circle {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self {
             cx: None,
@@ -38,11 +37,11 @@ impl Circle {
     ///
     /// - center: For `cx` and `cy` attributes
     /// - radius: For `r` attributes
-    pub fn new<TCX, TCY, TR>(center: (&TCX, &TCY), radius: &TR) -> Self
+    pub fn new<TCX, TCY, TR>(center: (TCX, TCY), radius: TR) -> Self
     where
-        TCX: ToString,
-        TCY: ToString,
-        TR: ToString,
+        TCX: Into<Length>,
+        TCY: Into<Length>,
+        TR: Into<Length>,
     {
         let mut c = Circle::default();
 
@@ -54,26 +53,53 @@ impl Circle {
         c
     }
 
-    impl_accessor!(string* -> cx, set_cx, "cx");
-    impl_accessor!(string* -> cy, set_cy, "cy");
-    impl_accessor!(string* -> radius, set_radius, "r");
+    impl_accessor!(length -> cx, set_cx, "cx");
+    impl_accessor!(length -> cy, set_cy, "cy");
+    impl_accessor!(length -> radius, set_radius, "r");
 }
 
 impl_element!(Circle, "circle");
 impl_attr_accessors!(Circle);
 
 impl WriteXml for Circle {
-    fn write_xml(&self, writer: &mut quick_xml::Writer<std::io::Cursor<Vec<u8>>>) -> Result<(), crate::error::Error> {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
         let tag = Self::tag_name();
 
         let mut bs = BytesStart::new(tag);
 
-        push_attr!(self.cx, bs, "cx" <- String);
-        push_attr!(self.cy, bs, "cy" <- String);
-        push_attr!(self.radius, bs, "r" <- String);
+        push_attr!(self.cx, bs, "cx" <- Format(options));
+        push_attr!(self.cy, bs, "cy" <- Format(options));
+        push_attr!(self.radius, bs, "r" <- Format(options));
 
-        push_attr!(map: self.attr, bs);
+        push_attr!(map: self.attr, bs, options);
 
-        convert_into_xml(writer, bs, self.children(), tag)
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Circle {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "cx" => pull_attr!(self.cx, value <- FromStr | map: self, key),
+            "cy" => pull_attr!(self.cy, value <- FromStr | map: self, key),
+            "r" => pull_attr!(self.radius, value <- FromStr | map: self, key),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Circle {
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        let matrix = local_matrix(self, matrix)?;
+
+        let center = Point(
+            self.cx.unwrap_or_default().to_absolute_user_units()?,
+            self.cy.unwrap_or_default().to_absolute_user_units()?,
+        );
+        let radius = self.radius?.to_absolute_user_units()?;
+
+        ellipse_bbox(&matrix, center, radius, radius)
     }
 }
@@ -0,0 +1,93 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, transform::Matrix, units::Units, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Clipping path element (`<clipPath>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/clipPath).
+pub struct ClipPath {
+    clip_path_units: Option<Units>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for ClipPath {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            clip_path_units: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl ClipPath {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    impl_accessor!(primitive -> clip_path_units, set_clip_path_units, Units, "clipPathUnits");
+}
+
+impl_element!(ClipPath, "clipPath");
+impl_attr_accessors!(ClipPath);
+
+impl WriteXml for ClipPath {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.clip_path_units, bs, "clipPathUnits" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for ClipPath {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "clipPathUnits" => pull_attr!(self.clip_path_units, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for ClipPath {
+    /// Clipping paths are not rendered.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::element::{Mask, Svg};
+
+    use super::*;
+
+    #[test]
+    fn clip_and_mask() {
+        let mut clip = ClipPath::new();
+        clip.set_clip_path_units(Some(Units::ObjectBoundingBox));
+        assert_eq!(clip.to_string(), r#"<clipPath clipPathUnits="objectBoundingBox"/>"#);
+
+        let mut mask = Mask::new();
+        mask.set_mask_units(Some(Units::UserSpaceOnUse)).set_x(Some(0)).set_width(Some(crate::attr::length::Length::percent(50.0)));
+        assert_eq!(mask.to_string(), r#"<mask maskUnits="userSpaceOnUse" x="0" width="50%"/>"#);
+
+        let src = r#"<svg xmlns="http://www.w3.org/2000/svg"><defs><clipPath id="c" clipPathUnits="userSpaceOnUse"><rect x="0" y="0" width="5" height="5"/></clipPath><mask maskContentUnits="objectBoundingBox"/></defs></svg>"#;
+        let expected = src.replace(r#"id="c" clipPathUnits="userSpaceOnUse""#, r#"clipPathUnits="userSpaceOnUse" id="c""#);
+        assert_eq!(src.parse::<Svg>().unwrap().to_string(), expected);
+    }
+}
@@ -0,0 +1,107 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, AccessAttr, Attr, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_element, ChildKind, ChildList, ElementKind, LazyChildList, ReadXml, TagName, Use, WriteXml};
+
+/// Definitions element (`<defs>`)
+///
+/// Content of `<defs>` is not rendered directly, but referenced by other elements.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/defs).
+pub struct Defs {
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Defs {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Defs {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an element in the definitions and return a `<use>` referencing it.
+    ///
+    /// The element keeps its `id` if it has one, otherwise it is given
+    /// an `id` made of its tag name and a number, unique in the definitions.
+    pub fn define<T>(&mut self, el: T) -> Use
+    where
+        T: Into<ElementKind>,
+    {
+        let mut el = el.into();
+        let id = ensure_id(&mut el, |id| contains_id(self.children(), id));
+
+        self.children.get_or_insert_with(Vec::new).push(ChildKind::Element(el));
+
+        Use::referencing(&id)
+    }
+}
+
+/// Whether an element in the tree has the `id`.
+pub(crate) fn contains_id(children: Option<&ChildList>, id: &str) -> bool {
+    children.is_some_and(|children| children.iter().any(|child| match child {
+        ChildKind::Element(el) => el.attr(&Attr::from("id")) == Some(id) || contains_id(el.children(), id),
+        ChildKind::String(_) => false,
+    }))
+}
+
+/// Get the `id` of the element, or give it one which is not `taken`.
+pub(crate) fn ensure_id<F>(el: &mut ElementKind, taken: F) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    // Looking up by key also finds the attribute without `attr-core`
+    if let Some(id) = el.attr(&Attr::from("id")) {
+        return id.to_string();
+    }
+
+    let id = (1..)
+        .map(|n| format!("{}{n}", el.tag()))
+        .find(|id| !taken(id))
+        .unwrap();
+
+    el.set_attr(Attr::from("id"), &id);
+
+    id
+}
+
+impl_element!(Defs, "defs");
+impl_attr_accessors!(Defs);
+
+impl WriteXml for Defs {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Defs {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        pull_attr!(map: self, key, value);
+
+        Ok(())
+    }
+}
+
+impl BBox for Defs {
+    /// Definitions are not rendered.
+    #[inline]
+    fn transformed_bbox(&self, _: &crate::attr::transform::Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
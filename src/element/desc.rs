@@ -0,0 +1,67 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, transform::Matrix, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_element, ChildKind, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Description element (`<desc>`), the accessible description of its parent
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/desc).
+pub struct Desc {
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Desc {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Desc {
+    /// Create an element holding `content` as text.
+    pub fn new<T>(content: &T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            children: Some(vec![ChildKind::String(content.to_string())]),
+            ..Default::default()
+        }
+    }
+}
+
+impl_element!(Desc, "desc");
+impl_attr_accessors!(Desc);
+
+impl WriteXml for Desc {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Desc {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        pull_attr!(map: self, key, value);
+
+        Ok(())
+    }
+}
+
+impl BBox for Desc {
+    /// Descriptions are not rendered.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
@@ -0,0 +1,133 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, length::Length, transform::Matrix, LazyAttrMap}, bbox::{ellipse_bbox, local_matrix, BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions, Point};
+
+use super::{impl_accessor, impl_element, ChildList, ReadXml, TagName, WriteXml};
+
+/// Ellipse element (`<ellipse>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/ellipse).
+pub struct Ellipse {
+    cx: Option<Length>,
+    cy: Option<Length>,
+    rx: Option<Length>,
+    ry: Option<Length>,
+
+    attr: LazyAttrMap,
+
+    children: Option<ChildList>,
+}
+
+impl Default for Ellipse {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            cx: None,
+            cy: None,
+            rx: None,
+            ry: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Ellipse {
+    /// Create a new ellipse
+    ///
+    /// ## Parameters
+    ///
+    /// - center: For `cx` and `cy` attributes
+    /// - radius: For `rx` and `ry` attributes
+    pub fn new<TCX, TCY, TRX, TRY>(center: (TCX, TCY), radius: (TRX, TRY)) -> Self
+    where
+        TCX: Into<Length>,
+        TCY: Into<Length>,
+        TRX: Into<Length>,
+        TRY: Into<Length>,
+    {
+        let mut e = Ellipse::default();
+
+        e.set_cx(Some(center.0));
+        e.set_cy(Some(center.1));
+
+        e.set_rx(Some(radius.0));
+        e.set_ry(Some(radius.1));
+
+        e
+    }
+
+    impl_accessor!(length -> cx, set_cx, "cx");
+    impl_accessor!(length -> cy, set_cy, "cy");
+    impl_accessor!(length -> rx, set_rx, "rx");
+    impl_accessor!(length -> ry, set_ry, "ry");
+}
+
+impl_element!(Ellipse, "ellipse");
+impl_attr_accessors!(Ellipse);
+
+impl WriteXml for Ellipse {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.cx, bs, "cx" <- Format(options));
+        push_attr!(self.cy, bs, "cy" <- Format(options));
+        push_attr!(self.rx, bs, "rx" <- Format(options));
+        push_attr!(self.ry, bs, "ry" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Ellipse {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "cx" => pull_attr!(self.cx, value <- FromStr | map: self, key),
+            "cy" => pull_attr!(self.cy, value <- FromStr | map: self, key),
+            "rx" => pull_attr!(self.rx, value <- FromStr | map: self, key),
+            "ry" => pull_attr!(self.ry, value <- FromStr | map: self, key),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Ellipse {
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        let matrix = local_matrix(self, matrix)?;
+
+        let center = Point(
+            self.cx.unwrap_or_default().to_absolute_user_units()?,
+            self.cy.unwrap_or_default().to_absolute_user_units()?,
+        );
+
+        // A missing radius takes the other one, as with `auto`
+        let (rx, ry) = match (self.rx, self.ry) {
+            (Some(rx), Some(ry)) => (rx, ry),
+            (Some(r), None) | (None, Some(r)) => (r, r),
+            (None, None) => return None,
+        };
+
+        ellipse_bbox(&matrix, center, rx.to_absolute_user_units()?, ry.to_absolute_user_units()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn write_and_parse() {
+        let ellipse = Ellipse::new((1, 2), (3, 4));
+        let expected = r#"<ellipse cx="1" cy="2" rx="3" ry="4"/>"#;
+
+        assert_eq!(ellipse.to_string(), expected);
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+        assert_eq!(crate::parser::parse(r#"<ellipse rx="auto" ry="2"/>"#).unwrap().to_string(), r#"<ellipse ry="2" rx="auto"/>"#);
+    }
+}
@@ -0,0 +1,108 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{filter::{BlendMode, FilterInput, FilterResult}, impl_attr_accessors, length::Length, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, filter::impl_filter_primitive, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Blend filter primitive (`<feBlend>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feBlend).
+pub struct FeBlend {
+    input: Option<FilterInput>,
+    in2: Option<FilterInput>,
+    mode: Option<BlendMode>,
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+    result: Option<FilterResult>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FeBlend {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            input: None,
+            in2: None,
+            mode: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            result: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FeBlend {
+    /// Blend `input` over `in2`.
+    pub fn new<I, J>(input: I, in2: J, mode: BlendMode) -> Self
+    where
+        I: Into<FilterInput>,
+        J: Into<FilterInput>,
+    {
+        Self {
+            input: Some(input.into()),
+            in2: Some(in2.into()),
+            mode: Some(mode),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(ref:move_setter -> input, set_input, "in", FilterInput);
+    impl_accessor!(ref:move_setter -> in2, set_in2, "in2", FilterInput);
+    impl_accessor!(primitive -> mode, set_mode, BlendMode, "mode");
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(ref:move_setter -> result, set_result, "result", FilterResult);
+}
+
+impl_element!(FeBlend, "feBlend");
+impl_attr_accessors!(FeBlend);
+impl_filter_primitive!(FeBlend);
+
+impl WriteXml for FeBlend {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.input, bs, "in" <- ToString);
+        push_attr!(self.in2, bs, "in2" <- ToString);
+        push_attr!(self.mode, bs, "mode" <- ToString);
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.result, bs, "result" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FeBlend {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "in" => pull_attr!(self.input, value <- FromStr),
+            "in2" => pull_attr!(self.in2, value <- FromStr),
+            "mode" => pull_attr!(self.mode, value <- FromStr),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "result" => pull_attr!(self.result, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
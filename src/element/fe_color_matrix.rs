@@ -0,0 +1,157 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{filter::{ColorMatrixType, FilterInput, FilterResult}, impl_attr_accessors, length::Length, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, filter::impl_filter_primitive, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Color matrix filter primitive (`<feColorMatrix>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feColorMatrix).
+pub struct FeColorMatrix {
+    input: Option<FilterInput>,
+    matrix_type: Option<ColorMatrixType>,
+    values: Option<Vec<f32>>,
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+    result: Option<FilterResult>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FeColorMatrix {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            input: None,
+            matrix_type: None,
+            values: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            result: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FeColorMatrix {
+    /// Transform colors of `input` with a 5x4 matrix given in row-major order.
+    pub fn new<I>(input: I, matrix: [f32; 20]) -> Self
+    where
+        I: Into<FilterInput>,
+    {
+        Self {
+            input: Some(input.into()),
+            matrix_type: Some(ColorMatrixType::Matrix),
+            values: Some(matrix.to_vec()),
+            ..Default::default()
+        }
+    }
+
+    /// Saturate `input`, from `0.0` (grayscale) to `1.0` (unchanged).
+    pub fn saturate<I>(input: I, saturation: f32) -> Self
+    where
+        I: Into<FilterInput>,
+    {
+        Self {
+            input: Some(input.into()),
+            matrix_type: Some(ColorMatrixType::Saturate),
+            values: Some(vec![saturation]),
+            ..Default::default()
+        }
+    }
+
+    /// Rotate hues of `input` by an angle in degrees.
+    pub fn hue_rotate<I>(input: I, angle: f32) -> Self
+    where
+        I: Into<FilterInput>,
+    {
+        Self {
+            input: Some(input.into()),
+            matrix_type: Some(ColorMatrixType::HueRotate),
+            values: Some(vec![angle]),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(ref:move_setter -> input, set_input, "in", FilterInput);
+    impl_accessor!(primitive -> matrix_type, set_matrix_type, ColorMatrixType, "type");
+    impl_accessor!(list:primitive -> values, values_mut, "values", f32);
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(ref:move_setter -> result, set_result, "result", FilterResult);
+}
+
+impl_element!(FeColorMatrix, "feColorMatrix");
+impl_attr_accessors!(FeColorMatrix);
+impl_filter_primitive!(FeColorMatrix);
+
+impl WriteXml for FeColorMatrix {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.input, bs, "in" <- ToString);
+        push_attr!(self.matrix_type, bs, "type" <- ToString);
+        push_attr!(self.values, bs, "values" <- Formats(options) | " ");
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.result, bs, "result" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FeColorMatrix {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "in" => pull_attr!(self.input, value <- FromStr),
+            "type" => pull_attr!(self.matrix_type, value <- FromStr),
+            "values" => pull_attr!(self.values, value <- primitives),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "result" => pull_attr!(self.result, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn write_and_parse() {
+        let mut matrix = [0.0; 20];
+        matrix[3] = 1.0;
+        matrix[18] = 0.5;
+
+        let expected = r#"<feColorMatrix in="SourceGraphic" type="matrix" values="0 0 0 1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0.5 0"/>"#;
+
+        assert_eq!(FeColorMatrix::new(FilterInput::SourceGraphic, matrix).to_string(), expected);
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+
+        let mut saturate = FeColorMatrix::saturate(FilterInput::SourceGraphic, 0.2);
+        saturate.values_mut()[0] = 0.3;
+        assert_eq!(saturate.to_string(), r#"<feColorMatrix in="SourceGraphic" type="saturate" values="0.3"/>"#);
+
+        let expected = r#"<feColorMatrix type="luminanceToAlpha"/>"#;
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+    }
+}
@@ -0,0 +1,128 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{filter::{CompositeOperator, FilterInput, FilterResult}, impl_attr_accessors, length::Length, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, filter::impl_filter_primitive, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Composite filter primitive (`<feComposite>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feComposite).
+pub struct FeComposite {
+    input: Option<FilterInput>,
+    in2: Option<FilterInput>,
+    operator: Option<CompositeOperator>,
+    k1: Option<f32>,
+    k2: Option<f32>,
+    k3: Option<f32>,
+    k4: Option<f32>,
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+    result: Option<FilterResult>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FeComposite {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            input: None,
+            in2: None,
+            operator: None,
+            k1: None,
+            k2: None,
+            k3: None,
+            k4: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            result: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FeComposite {
+    /// Combine `input` with `in2` using `operator`.
+    pub fn new<I, J>(input: I, in2: J, operator: CompositeOperator) -> Self
+    where
+        I: Into<FilterInput>,
+        J: Into<FilterInput>,
+    {
+        Self {
+            input: Some(input.into()),
+            in2: Some(in2.into()),
+            operator: Some(operator),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(ref:move_setter -> input, set_input, "in", FilterInput);
+    impl_accessor!(ref:move_setter -> in2, set_in2, "in2", FilterInput);
+    impl_accessor!(primitive -> operator, set_operator, CompositeOperator, "operator");
+    impl_accessor!(primitive -> k1, set_k1, f32, "k1");
+    impl_accessor!(primitive -> k2, set_k2, f32, "k2");
+    impl_accessor!(primitive -> k3, set_k3, f32, "k3");
+    impl_accessor!(primitive -> k4, set_k4, f32, "k4");
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(ref:move_setter -> result, set_result, "result", FilterResult);
+}
+
+impl_element!(FeComposite, "feComposite");
+impl_attr_accessors!(FeComposite);
+impl_filter_primitive!(FeComposite);
+
+impl WriteXml for FeComposite {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.input, bs, "in" <- ToString);
+        push_attr!(self.in2, bs, "in2" <- ToString);
+        push_attr!(self.operator, bs, "operator" <- ToString);
+        push_attr!(self.k1, bs, "k1" <- Format(options));
+        push_attr!(self.k2, bs, "k2" <- Format(options));
+        push_attr!(self.k3, bs, "k3" <- Format(options));
+        push_attr!(self.k4, bs, "k4" <- Format(options));
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.result, bs, "result" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FeComposite {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "in" => pull_attr!(self.input, value <- FromStr),
+            "in2" => pull_attr!(self.in2, value <- FromStr),
+            "operator" => pull_attr!(self.operator, value <- FromStr),
+            "k1" => pull_attr!(self.k1, value <- FromStr),
+            "k2" => pull_attr!(self.k2, value <- FromStr),
+            "k3" => pull_attr!(self.k3, value <- FromStr),
+            "k4" => pull_attr!(self.k4, value <- FromStr),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "result" => pull_attr!(self.result, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
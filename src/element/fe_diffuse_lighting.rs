@@ -0,0 +1,117 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{color::Color, filter::{FilterInput, FilterResult, NumberOptionalNumber}, impl_attr_accessors, length::Length, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, filter::impl_filter_primitive, filter::LightSource, ChildKind, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Diffuse lighting filter primitive (`<feDiffuseLighting>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDiffuseLighting).
+pub struct FeDiffuseLighting {
+    input: Option<FilterInput>,
+    surface_scale: Option<f32>,
+    diffuse_constant: Option<f32>,
+    kernel_unit_length: Option<NumberOptionalNumber>,
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+    result: Option<FilterResult>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FeDiffuseLighting {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            input: None,
+            surface_scale: None,
+            diffuse_constant: None,
+            kernel_unit_length: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            result: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FeDiffuseLighting {
+    /// Light the alpha channel of `input` as a bump map.
+    ///
+    /// The light source is the only child.
+    pub fn new<I, L>(input: I, light: L, surface_scale: f32, diffuse_constant: f32) -> Self
+    where
+        I: Into<FilterInput>,
+        L: LightSource,
+    {
+        Self {
+            input: Some(input.into()),
+            surface_scale: Some(surface_scale),
+            diffuse_constant: Some(diffuse_constant),
+            children: Some(vec![ChildKind::Element(light.into())]),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(ref:move_setter -> input, set_input, "in", FilterInput);
+    impl_accessor!(primitive -> surface_scale, set_surface_scale, f32, "surfaceScale");
+    impl_accessor!(primitive -> diffuse_constant, set_diffuse_constant, f32, "diffuseConstant");
+    impl_accessor!(primitive -> kernel_unit_length, set_kernel_unit_length, NumberOptionalNumber, "kernelUnitLength");
+    impl_accessor!(map -> lighting_color, set_lighting_color, Color, "lighting-color");
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(ref:move_setter -> result, set_result, "result", FilterResult);
+}
+
+impl_element!(FeDiffuseLighting, "feDiffuseLighting");
+impl_attr_accessors!(FeDiffuseLighting);
+impl_filter_primitive!(FeDiffuseLighting);
+
+impl WriteXml for FeDiffuseLighting {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.input, bs, "in" <- ToString);
+        push_attr!(self.surface_scale, bs, "surfaceScale" <- Format(options));
+        push_attr!(self.diffuse_constant, bs, "diffuseConstant" <- Format(options));
+        push_attr!(self.kernel_unit_length, bs, "kernelUnitLength" <- Format(options));
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.result, bs, "result" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FeDiffuseLighting {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "in" => pull_attr!(self.input, value <- FromStr),
+            "surfaceScale" => pull_attr!(self.surface_scale, value <- FromStr),
+            "diffuseConstant" => pull_attr!(self.diffuse_constant, value <- FromStr),
+            "kernelUnitLength" => pull_attr!(self.kernel_unit_length, value <- FromStr),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "result" => pull_attr!(self.result, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
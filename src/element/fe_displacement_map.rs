@@ -0,0 +1,136 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{filter::{ChannelSelector, FilterInput, FilterResult}, impl_attr_accessors, length::Length, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, filter::impl_filter_primitive, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Displacement map filter primitive (`<feDisplacementMap>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDisplacementMap).
+pub struct FeDisplacementMap {
+    input: Option<FilterInput>,
+    in2: Option<FilterInput>,
+    scale: Option<f32>,
+    x_channel_selector: Option<ChannelSelector>,
+    y_channel_selector: Option<ChannelSelector>,
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+    result: Option<FilterResult>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FeDisplacementMap {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            input: None,
+            in2: None,
+            scale: None,
+            x_channel_selector: None,
+            y_channel_selector: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            result: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FeDisplacementMap {
+    /// Displace pixels of `input` by the red and green channels of `in2`.
+    pub fn new<I, J>(input: I, in2: J, scale: f32) -> Self
+    where
+        I: Into<FilterInput>,
+        J: Into<FilterInput>,
+    {
+        Self {
+            input: Some(input.into()),
+            in2: Some(in2.into()),
+            scale: Some(scale),
+            x_channel_selector: Some(ChannelSelector::R),
+            y_channel_selector: Some(ChannelSelector::G),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(ref:move_setter -> input, set_input, "in", FilterInput);
+    impl_accessor!(ref:move_setter -> in2, set_in2, "in2", FilterInput);
+    impl_accessor!(primitive -> scale, set_scale, f32, "scale");
+    impl_accessor!(primitive -> x_channel_selector, set_x_channel_selector, ChannelSelector, "xChannelSelector");
+    impl_accessor!(primitive -> y_channel_selector, set_y_channel_selector, ChannelSelector, "yChannelSelector");
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(ref:move_setter -> result, set_result, "result", FilterResult);
+}
+
+impl_element!(FeDisplacementMap, "feDisplacementMap");
+impl_attr_accessors!(FeDisplacementMap);
+impl_filter_primitive!(FeDisplacementMap);
+
+impl WriteXml for FeDisplacementMap {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.input, bs, "in" <- ToString);
+        push_attr!(self.in2, bs, "in2" <- ToString);
+        push_attr!(self.scale, bs, "scale" <- Format(options));
+        push_attr!(self.x_channel_selector, bs, "xChannelSelector" <- ToString);
+        push_attr!(self.y_channel_selector, bs, "yChannelSelector" <- ToString);
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.result, bs, "result" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FeDisplacementMap {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "in" => pull_attr!(self.input, value <- FromStr),
+            "in2" => pull_attr!(self.in2, value <- FromStr),
+            "scale" => pull_attr!(self.scale, value <- FromStr),
+            "xChannelSelector" => pull_attr!(self.x_channel_selector, value <- FromStr),
+            "yChannelSelector" => pull_attr!(self.y_channel_selector, value <- FromStr),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "result" => pull_attr!(self.result, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn write_and_parse() {
+        let mut displacement = FeDisplacementMap::new(FilterInput::SourceGraphic, FilterResult::new("noise"), 10.0);
+        displacement.set_y_channel_selector(Some(ChannelSelector::A));
+
+        let expected = r#"<feDisplacementMap in="SourceGraphic" in2="noise" scale="10" xChannelSelector="R" yChannelSelector="A"/>"#;
+
+        assert_eq!(displacement.to_string(), expected);
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+    }
+}
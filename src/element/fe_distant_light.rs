@@ -0,0 +1,75 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, LazyAttrMap}, bbox::impl_no_bbox, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, filter::LightSource, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Distant light source (`<feDistantLight>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDistantLight).
+pub struct FeDistantLight {
+    azimuth: Option<f32>,
+    elevation: Option<f32>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FeDistantLight {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            azimuth: None,
+            elevation: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FeDistantLight {
+    /// Create a light infinitely far away, with angles in degrees.
+    pub fn new(azimuth: f32, elevation: f32) -> Self {
+        Self {
+            azimuth: Some(azimuth),
+            elevation: Some(elevation),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(primitive -> azimuth, set_azimuth, f32, "azimuth");
+    impl_accessor!(primitive -> elevation, set_elevation, f32, "elevation");
+}
+
+impl_element!(FeDistantLight, "feDistantLight");
+impl_attr_accessors!(FeDistantLight);
+impl LightSource for FeDistantLight {}
+
+impl WriteXml for FeDistantLight {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.azimuth, bs, "azimuth" <- Format(options));
+        push_attr!(self.elevation, bs, "elevation" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FeDistantLight {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "azimuth" => pull_attr!(self.azimuth, value <- FromStr),
+            "elevation" => pull_attr!(self.elevation, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl_no_bbox!(light_source: FeDistantLight);
@@ -0,0 +1,138 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{color::Color, filter::{FilterInput, FilterResult, NumberOptionalNumber}, impl_attr_accessors, length::Length, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, filter::impl_filter_primitive, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Drop shadow filter primitive (`<feDropShadow>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDropShadow).
+pub struct FeDropShadow {
+    input: Option<FilterInput>,
+    dx: Option<f32>,
+    dy: Option<f32>,
+    std_deviation: Option<NumberOptionalNumber>,
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+    result: Option<FilterResult>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FeDropShadow {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            input: None,
+            dx: None,
+            dy: None,
+            std_deviation: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            result: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FeDropShadow {
+    /// Create a shadow of the source graphic.
+    pub fn new<N, C>(dx: f32, dy: f32, std_deviation: N, color: C) -> Self
+    where
+        N: Into<NumberOptionalNumber>,
+        C: Into<Color>,
+    {
+        let mut shadow = Self {
+            dx: Some(dx),
+            dy: Some(dy),
+            std_deviation: Some(std_deviation.into()),
+            ..Default::default()
+        };
+
+        shadow.set_flood_color(Some(color.into()));
+
+        shadow
+    }
+
+    impl_accessor!(ref:move_setter -> input, set_input, "in", FilterInput);
+    impl_accessor!(primitive -> dx, set_dx, f32, "dx");
+    impl_accessor!(primitive -> dy, set_dy, f32, "dy");
+    impl_accessor!(primitive -> std_deviation, set_std_deviation, NumberOptionalNumber, "stdDeviation");
+    impl_accessor!(map -> flood_color, set_flood_color, Color, "flood-color");
+    impl_accessor!(map -> flood_opacity, set_flood_opacity, f32, "flood-opacity");
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(ref:move_setter -> result, set_result, "result", FilterResult);
+}
+
+impl_element!(FeDropShadow, "feDropShadow");
+impl_attr_accessors!(FeDropShadow);
+impl_filter_primitive!(FeDropShadow);
+
+impl WriteXml for FeDropShadow {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.input, bs, "in" <- ToString);
+        push_attr!(self.dx, bs, "dx" <- Format(options));
+        push_attr!(self.dy, bs, "dy" <- Format(options));
+        push_attr!(self.std_deviation, bs, "stdDeviation" <- Format(options));
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.result, bs, "result" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FeDropShadow {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "in" => pull_attr!(self.input, value <- FromStr),
+            "dx" => pull_attr!(self.dx, value <- FromStr),
+            "dy" => pull_attr!(self.dy, value <- FromStr),
+            "stdDeviation" => pull_attr!(self.std_deviation, value <- FromStr),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "result" => pull_attr!(self.result, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::attr::color::Rgba;
+
+    use super::*;
+
+    #[test]
+    fn write_and_parse() {
+        let mut shadow = FeDropShadow::new(1.0, 2.0, (3.0, 1.5), Rgba::new(0, 0, 0, 0.5));
+        shadow.set_input(FilterInput::SourceAlpha).set_flood_opacity(Some(0.8));
+
+        let expected = r#"<feDropShadow in="SourceAlpha" dx="1" dy="2" stdDeviation="3 1.5" flood-color="rgba(0,0,0,0.5)" flood-opacity="0.8"/>"#;
+
+        assert_eq!(shadow.to_string(), expected);
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+        assert_eq!(shadow.flood_color(), Some(Rgba::new(0, 0, 0, 0.5).into()));
+    }
+}
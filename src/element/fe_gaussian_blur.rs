@@ -0,0 +1,107 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{filter::{EdgeMode, FilterInput, FilterResult, NumberOptionalNumber}, impl_attr_accessors, length::Length, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, filter::impl_filter_primitive, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Gaussian blur filter primitive (`<feGaussianBlur>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feGaussianBlur).
+pub struct FeGaussianBlur {
+    input: Option<FilterInput>,
+    std_deviation: Option<NumberOptionalNumber>,
+    edge_mode: Option<EdgeMode>,
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+    result: Option<FilterResult>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FeGaussianBlur {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            input: None,
+            std_deviation: None,
+            edge_mode: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            result: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FeGaussianBlur {
+    /// Blur `input` with a standard deviation, given for both axes or as `(x, y)`.
+    pub fn new<I, N>(input: I, std_deviation: N) -> Self
+    where
+        I: Into<FilterInput>,
+        N: Into<NumberOptionalNumber>,
+    {
+        Self {
+            input: Some(input.into()),
+            std_deviation: Some(std_deviation.into()),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(ref:move_setter -> input, set_input, "in", FilterInput);
+    impl_accessor!(primitive -> std_deviation, set_std_deviation, NumberOptionalNumber, "stdDeviation");
+    impl_accessor!(primitive -> edge_mode, set_edge_mode, EdgeMode, "edgeMode");
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(ref:move_setter -> result, set_result, "result", FilterResult);
+}
+
+impl_element!(FeGaussianBlur, "feGaussianBlur");
+impl_attr_accessors!(FeGaussianBlur);
+impl_filter_primitive!(FeGaussianBlur);
+
+impl WriteXml for FeGaussianBlur {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.input, bs, "in" <- ToString);
+        push_attr!(self.std_deviation, bs, "stdDeviation" <- Format(options));
+        push_attr!(self.edge_mode, bs, "edgeMode" <- ToString);
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.result, bs, "result" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FeGaussianBlur {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "in" => pull_attr!(self.input, value <- FromStr),
+            "stdDeviation" => pull_attr!(self.std_deviation, value <- FromStr),
+            "edgeMode" => pull_attr!(self.edge_mode, value <- FromStr),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "result" => pull_attr!(self.result, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
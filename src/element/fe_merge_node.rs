@@ -0,0 +1,70 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{filter::FilterInput, impl_attr_accessors, LazyAttrMap}, bbox::impl_no_bbox, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Input of a merge filter primitive (`<feMergeNode>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feMergeNode).
+pub struct FeMergeNode {
+    input: Option<FilterInput>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FeMergeNode {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            input: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FeMergeNode {
+    pub fn new<I>(input: I) -> Self
+    where
+        I: Into<FilterInput>,
+    {
+        Self {
+            input: Some(input.into()),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(ref:move_setter -> input, set_input, "in", FilterInput);
+}
+
+impl_element!(FeMergeNode, "feMergeNode");
+impl_attr_accessors!(FeMergeNode);
+
+impl WriteXml for FeMergeNode {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.input, bs, "in" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FeMergeNode {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "in" => pull_attr!(self.input, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl_no_bbox!(filter_primitive: FeMergeNode);
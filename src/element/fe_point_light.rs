@@ -0,0 +1,81 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, LazyAttrMap}, bbox::impl_no_bbox, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, filter::LightSource, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Point light source (`<fePointLight>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/fePointLight).
+pub struct FePointLight {
+    x: Option<f32>,
+    y: Option<f32>,
+    z: Option<f32>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FePointLight {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            x: None,
+            y: None,
+            z: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FePointLight {
+    /// Create a light at a position in the user space of the filtered element.
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(primitive -> x, set_x, f32, "x");
+    impl_accessor!(primitive -> y, set_y, f32, "y");
+    impl_accessor!(primitive -> z, set_z, f32, "z");
+}
+
+impl_element!(FePointLight, "fePointLight");
+impl_attr_accessors!(FePointLight);
+impl LightSource for FePointLight {}
+
+impl WriteXml for FePointLight {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.z, bs, "z" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FePointLight {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "x" => pull_attr!(self.x, value <- FromStr),
+            "y" => pull_attr!(self.y, value <- FromStr),
+            "z" => pull_attr!(self.z, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl_no_bbox!(light_source: FePointLight);
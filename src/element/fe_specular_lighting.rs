@@ -0,0 +1,152 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{color::Color, filter::{FilterInput, FilterResult, NumberOptionalNumber}, impl_attr_accessors, length::Length, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, filter::impl_filter_primitive, filter::LightSource, ChildKind, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Specular lighting filter primitive (`<feSpecularLighting>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feSpecularLighting).
+pub struct FeSpecularLighting {
+    input: Option<FilterInput>,
+    surface_scale: Option<f32>,
+    specular_constant: Option<f32>,
+    specular_exponent: Option<f32>,
+    kernel_unit_length: Option<NumberOptionalNumber>,
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+    result: Option<FilterResult>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FeSpecularLighting {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            input: None,
+            surface_scale: None,
+            specular_constant: None,
+            specular_exponent: None,
+            kernel_unit_length: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            result: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FeSpecularLighting {
+    /// Light the alpha channel of `input` as a bump map.
+    ///
+    /// The light source is the only child.
+    pub fn new<I, L>(input: I, light: L, surface_scale: f32, specular_constant: f32, specular_exponent: f32) -> Self
+    where
+        I: Into<FilterInput>,
+        L: LightSource,
+    {
+        Self {
+            input: Some(input.into()),
+            surface_scale: Some(surface_scale),
+            specular_constant: Some(specular_constant),
+            specular_exponent: Some(specular_exponent),
+            children: Some(vec![ChildKind::Element(light.into())]),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(ref:move_setter -> input, set_input, "in", FilterInput);
+    impl_accessor!(primitive -> surface_scale, set_surface_scale, f32, "surfaceScale");
+    impl_accessor!(primitive -> specular_constant, set_specular_constant, f32, "specularConstant");
+    impl_accessor!(primitive -> specular_exponent, set_specular_exponent, f32, "specularExponent");
+    impl_accessor!(primitive -> kernel_unit_length, set_kernel_unit_length, NumberOptionalNumber, "kernelUnitLength");
+    impl_accessor!(map -> lighting_color, set_lighting_color, Color, "lighting-color");
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(ref:move_setter -> result, set_result, "result", FilterResult);
+}
+
+impl_element!(FeSpecularLighting, "feSpecularLighting");
+impl_attr_accessors!(FeSpecularLighting);
+impl_filter_primitive!(FeSpecularLighting);
+
+impl WriteXml for FeSpecularLighting {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.input, bs, "in" <- ToString);
+        push_attr!(self.surface_scale, bs, "surfaceScale" <- Format(options));
+        push_attr!(self.specular_constant, bs, "specularConstant" <- Format(options));
+        push_attr!(self.specular_exponent, bs, "specularExponent" <- Format(options));
+        push_attr!(self.kernel_unit_length, bs, "kernelUnitLength" <- Format(options));
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.result, bs, "result" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FeSpecularLighting {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "in" => pull_attr!(self.input, value <- FromStr),
+            "surfaceScale" => pull_attr!(self.surface_scale, value <- FromStr),
+            "specularConstant" => pull_attr!(self.specular_constant, value <- FromStr),
+            "specularExponent" => pull_attr!(self.specular_exponent, value <- FromStr),
+            "kernelUnitLength" => pull_attr!(self.kernel_unit_length, value <- FromStr),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "result" => pull_attr!(self.result, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::element::{FePointLight, FeSpotLight};
+
+    use super::*;
+
+    #[test]
+    fn write_and_parse() {
+        let mut light = FeSpotLight::new((0.0, 0.0, 50.0), (10.0, 10.0, 0.0));
+        light.set_specular_exponent(Some(2.0)).set_limiting_cone_angle(Some(30.0));
+
+        let mut lighting = FeSpecularLighting::new(FilterInput::SourceAlpha, light, 2.0, 0.75, 20.0);
+        lighting.set_kernel_unit_length(Some((1.0, 2.0).into()))
+            .set_lighting_color(Some(Color::rgb(255, 255, 255)));
+
+        let expected = concat!(
+            r##"<feSpecularLighting in="SourceAlpha" surfaceScale="2" specularConstant="0.75" specularExponent="20" kernelUnitLength="1 2" lighting-color="#fff">"##,
+            r#"<feSpotLight x="0" y="0" z="50" pointsAtX="10" pointsAtY="10" pointsAtZ="0" specularExponent="2" limitingConeAngle="30"/></feSpecularLighting>"#,
+        );
+
+        assert_eq!(lighting.to_string(), expected);
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+
+        let expected = r#"<feSpecularLighting><fePointLight x="1" y="2" z="3"/></feSpecularLighting>"#;
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+        assert_eq!(FePointLight::new(1.0, 2.0, 3.0).to_string(), r#"<fePointLight x="1" y="2" z="3"/>"#);
+    }
+}
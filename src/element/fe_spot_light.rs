@@ -0,0 +1,109 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, LazyAttrMap}, bbox::impl_no_bbox, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, filter::LightSource, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Spot light source (`<feSpotLight>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feSpotLight).
+pub struct FeSpotLight {
+    x: Option<f32>,
+    y: Option<f32>,
+    z: Option<f32>,
+    points_at_x: Option<f32>,
+    points_at_y: Option<f32>,
+    points_at_z: Option<f32>,
+    specular_exponent: Option<f32>,
+    limiting_cone_angle: Option<f32>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for FeSpotLight {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            x: None,
+            y: None,
+            z: None,
+            points_at_x: None,
+            points_at_y: None,
+            points_at_z: None,
+            specular_exponent: None,
+            limiting_cone_angle: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl FeSpotLight {
+    /// Create a light at `(x, y, z)` pointing at `points_at`.
+    pub fn new((x, y, z): (f32, f32, f32), points_at: (f32, f32, f32)) -> Self {
+        Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            points_at_x: Some(points_at.0),
+            points_at_y: Some(points_at.1),
+            points_at_z: Some(points_at.2),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(primitive -> x, set_x, f32, "x");
+    impl_accessor!(primitive -> y, set_y, f32, "y");
+    impl_accessor!(primitive -> z, set_z, f32, "z");
+    impl_accessor!(primitive -> points_at_x, set_points_at_x, f32, "pointsAtX");
+    impl_accessor!(primitive -> points_at_y, set_points_at_y, f32, "pointsAtY");
+    impl_accessor!(primitive -> points_at_z, set_points_at_z, f32, "pointsAtZ");
+    impl_accessor!(primitive -> specular_exponent, set_specular_exponent, f32, "specularExponent");
+    impl_accessor!(primitive -> limiting_cone_angle, set_limiting_cone_angle, f32, "limitingConeAngle");
+}
+
+impl_element!(FeSpotLight, "feSpotLight");
+impl_attr_accessors!(FeSpotLight);
+impl LightSource for FeSpotLight {}
+
+impl WriteXml for FeSpotLight {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.z, bs, "z" <- Format(options));
+        push_attr!(self.points_at_x, bs, "pointsAtX" <- Format(options));
+        push_attr!(self.points_at_y, bs, "pointsAtY" <- Format(options));
+        push_attr!(self.points_at_z, bs, "pointsAtZ" <- Format(options));
+        push_attr!(self.specular_exponent, bs, "specularExponent" <- Format(options));
+        push_attr!(self.limiting_cone_angle, bs, "limitingConeAngle" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for FeSpotLight {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "x" => pull_attr!(self.x, value <- FromStr),
+            "y" => pull_attr!(self.y, value <- FromStr),
+            "z" => pull_attr!(self.z, value <- FromStr),
+            "pointsAtX" => pull_attr!(self.points_at_x, value <- FromStr),
+            "pointsAtY" => pull_attr!(self.points_at_y, value <- FromStr),
+            "pointsAtZ" => pull_attr!(self.points_at_z, value <- FromStr),
+            "specularExponent" => pull_attr!(self.specular_exponent, value <- FromStr),
+            "limitingConeAngle" => pull_attr!(self.limiting_cone_angle, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl_no_bbox!(light_source: FeSpotLight);
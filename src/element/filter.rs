@@ -0,0 +1,342 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{color::Color, filter::{CompositeOperator, FilterInput, FilterResult, NumberOptionalNumber}, impl_attr_accessors, length::Length, transform::Matrix, units::Units, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, error::Error, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, ChildKind, ElementKind, FeComposite, FeFlood, FeGaussianBlur, FeMerge, FeOffset, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Filter primitive element, whose output can be named by `result`
+/// and fed into the `in` or `in2` of later primitives.
+pub trait FilterPrimitive: Into<ElementKind> {
+    /// Access the name of the output.
+    fn result(&self) -> Option<&FilterResult>;
+
+    /// Name the output.
+    fn set_result(&mut self, value: FilterResult);
+}
+
+macro_rules! impl_filter_primitive {
+    ($name:ident) => {
+        impl crate::element::filter::FilterPrimitive for $name {
+            #[inline]
+            fn result(&self) -> Option<&FilterResult> {
+                self.result.as_ref()
+            }
+
+            #[inline]
+            fn set_result(&mut self, value: FilterResult) {
+                self.result = Some(value);
+            }
+        }
+
+        crate::bbox::impl_no_bbox!(filter_primitive: $name);
+    };
+}
+
+pub(crate) use impl_filter_primitive;
+
+/// Access the name of the output of a filter primitive.
+fn result_of(el: &ElementKind) -> Option<&FilterResult> {
+    match el {
+        ElementKind::FeBlend(inner) => inner.result(),
+        ElementKind::FeColorMatrix(inner) => inner.result(),
+        ElementKind::FeComposite(inner) => inner.result(),
+        ElementKind::FeDiffuseLighting(inner) => inner.result(),
+        ElementKind::FeDisplacementMap(inner) => inner.result(),
+        ElementKind::FeDropShadow(inner) => inner.result(),
+        ElementKind::FeFlood(inner) => inner.result(),
+        ElementKind::FeGaussianBlur(inner) => inner.result(),
+        ElementKind::FeMerge(inner) => inner.result(),
+        ElementKind::FeMorphology(inner) => inner.result(),
+        ElementKind::FeOffset(inner) => inner.result(),
+        ElementKind::FeSpecularLighting(inner) => inner.result(),
+        ElementKind::FeTurbulence(inner) => inner.result(),
+        _ => None,
+    }
+}
+
+/// Light source of `<feDiffuseLighting>` and `<feSpecularLighting>`
+pub trait LightSource: Into<ElementKind> {}
+
+/// Filter element (`<filter>`)
+///
+/// Primitives are chained by pushing them in order: [`Filter::push`] names
+/// the output of a primitive and returns a handle to be used as the input
+/// of the following ones.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/filter).
+pub struct Filter {
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+    filter_units: Option<Units>,
+    primitive_units: Option<Units>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Filter {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            filter_units: None,
+            primitive_units: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Filter {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Blur the source graphic.
+    pub fn blur<N>(std_deviation: N) -> Self
+    where
+        N: Into<NumberOptionalNumber>,
+    {
+        let mut filter = Self::new();
+        filter.append(FeGaussianBlur::new(FilterInput::SourceGraphic, std_deviation));
+        filter
+    }
+
+    /// Draw the source graphic over its blurred silhouette, shifted by `(dx, dy)`
+    /// and painted with `color`.
+    pub fn drop_shadow<N, C>(dx: f32, dy: f32, std_deviation: N, color: C) -> Self
+    where
+        N: Into<NumberOptionalNumber>,
+        C: Into<Color>,
+    {
+        Self::halo(Some((dx, dy)), std_deviation.into(), color.into())
+    }
+
+    /// Draw the source graphic over its blurred silhouette painted with `color`.
+    pub fn glow<N, C>(std_deviation: N, color: C) -> Self
+    where
+        N: Into<NumberOptionalNumber>,
+        C: Into<Color>,
+    {
+        Self::halo(None, std_deviation.into(), color.into())
+    }
+
+    fn halo(offset: Option<(f32, f32)>, std_deviation: NumberOptionalNumber, color: Color) -> Self {
+        let mut filter = Self::new();
+
+        let mut silhouette = filter.push(FeGaussianBlur::new(FilterInput::SourceAlpha, std_deviation));
+
+        if let Some((dx, dy)) = offset {
+            silhouette = filter.push(FeOffset::new(&silhouette, dx, dy));
+        }
+
+        let flood = filter.push(FeFlood::new(color));
+        let shadow = filter.push(FeComposite::new(&flood, &silhouette, CompositeOperator::In));
+
+        filter.append(FeMerge::with_inputs([shadow.into(), FilterInput::SourceGraphic]));
+        filter
+    }
+
+    /// Append a primitive, naming its output `result` and a number if not
+    /// named yet, unique among the outputs of the previous primitives.
+    ///
+    /// Returns the name of the output.
+    pub fn push<P>(&mut self, mut primitive: P) -> FilterResult
+    where
+        P: FilterPrimitive,
+    {
+        let result = match primitive.result() {
+            Some(result) => result.clone(),
+            None => {
+                let taken: Vec<&str> = self.children().into_iter().flatten()
+                    .filter_map(|child| match child {
+                        ChildKind::Element(el) => result_of(el),
+                        _ => None,
+                    })
+                    .map(FilterResult::name)
+                    .collect();
+
+                let name = (1..)
+                    .map(|n| format!("result{n}"))
+                    .find(|name| !taken.contains(&name.as_str()))
+                    .unwrap();
+
+                let result = FilterResult::new(&name);
+                primitive.set_result(result.clone());
+                result
+            }
+        };
+
+        self.children.get_or_insert_with(Vec::new).push(ChildKind::Element(primitive.into()));
+
+        result
+    }
+
+    /// Append a primitive without naming its output, e.g. the last one.
+    pub fn append<P>(&mut self, primitive: P) -> &mut Self
+    where
+        P: FilterPrimitive,
+    {
+        self.children.get_or_insert_with(Vec::new).push(ChildKind::Element(primitive.into()));
+        self
+    }
+
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(primitive -> filter_units, set_filter_units, Units, "filterUnits");
+    impl_accessor!(primitive -> primitive_units, set_primitive_units, Units, "primitiveUnits");
+}
+
+impl_element!(Filter, "filter");
+impl_attr_accessors!(Filter);
+
+impl WriteXml for Filter {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.filter_units, bs, "filterUnits" <- ToString);
+        push_attr!(self.primitive_units, bs, "primitiveUnits" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Filter {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "filterUnits" => pull_attr!(self.filter_units, value <- FromStr),
+            "primitiveUnits" => pull_attr!(self.primitive_units, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Filter {
+    /// Filters are only applied to other elements.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{attr::{color::Rgba, filter::BlendMode, AccessAttr, Attr}, element::{FeBlend, FeDiffuseLighting, FeDistantLight, Rect, Svg}};
+
+    use super::*;
+
+    #[test]
+    fn chain_primitives() {
+        let mut filter = Filter::new();
+
+        let blur = filter.push(FeGaussianBlur::new(FilterInput::SourceGraphic, (2.0, 0.5)));
+        let light = filter.push(FeDiffuseLighting::new(&blur, FeDistantLight::new(45.0, 30.0), 1.0, 1.5));
+        filter.append(FeBlend::new(&light, FilterInput::SourceGraphic, BlendMode::Multiply));
+
+        let expected = concat!(
+            r#"<filter><feGaussianBlur in="SourceGraphic" stdDeviation="2 0.5" result="result1"/>"#,
+            r#"<feDiffuseLighting in="result1" surfaceScale="1" diffuseConstant="1.5" result="result2"><feDistantLight azimuth="45" elevation="30"/></feDiffuseLighting>"#,
+            r#"<feBlend in="result2" in2="SourceGraphic" mode="multiply"/></filter>"#,
+        );
+
+        assert_eq!(filter.to_string(), expected);
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+
+        assert_eq!("BackgroundAlpha".parse::<FilterInput>().unwrap(), FilterInput::BackgroundAlpha);
+        assert_eq!("shadow".parse::<FilterInput>().unwrap(), FilterResult::new("shadow").into());
+        assert!("1 2 3".parse::<NumberOptionalNumber>().is_err());
+    }
+
+    #[test]
+    fn share_presentation_attributes() {
+        let src = r##"<filter><feFlood flood-color="red" flood-opacity="0.5"/><feDiffuseLighting lighting-color="#fff"/></filter>"##;
+        let ElementKind::Filter(mut filter) = crate::parser::parse(src).unwrap() else {
+            unreachable!()
+        };
+
+        let children = filter.children_mut().unwrap();
+
+        let ChildKind::Element(flood) = &mut children[0] else {
+            unreachable!()
+        };
+
+        assert_eq!(flood.attr(&Attr::from("flood-opacity")), Some("0.5"));
+        #[cfg(feature = "attr-presentation")]
+        {
+            assert_eq!(flood.flood_color().unwrap().unwrap(), Color::rgb(255, 0, 0));
+            flood.set_flood_color(&Color::rgb(0, 0, 255));
+        }
+        #[cfg(not(feature = "attr-presentation"))]
+        flood.set_attr(Attr::from("flood-color"), "#00f");
+
+        let ChildKind::Element(ElementKind::FeDiffuseLighting(lighting)) = &mut children[1] else {
+            unreachable!()
+        };
+
+        assert_eq!(lighting.lighting_color(), Some(Color::rgb(255, 255, 255)));
+        lighting.set_lighting_color(None);
+
+        assert_eq!(filter.to_string(), r##"<filter><feFlood flood-color="#00f" flood-opacity="0.5"/><feDiffuseLighting/></filter>"##);
+    }
+
+    #[test]
+    fn unique_result_names() {
+        let mut filter = Filter::new();
+
+        let mut named = FeOffset::new(FilterInput::SourceGraphic, 1.0, 1.0);
+        named.set_result(FilterResult::new("result2"));
+
+        assert_eq!(filter.push(named).name(), "result2");
+        assert_eq!(filter.push(FeFlood::new(Color::rgb(0, 0, 0))).name(), "result1");
+        assert_eq!(filter.push(FeFlood::new(Color::rgb(0, 0, 0))).name(), "result3");
+    }
+
+    #[test]
+    fn recipes() {
+        assert_eq!(Filter::blur(3.0).to_string(), r#"<filter><feGaussianBlur in="SourceGraphic" stdDeviation="3"/></filter>"#);
+
+        let mut svg = Svg::new();
+        let id = svg.define_id(Filter::drop_shadow(2.0, 2.0, 1.5, Rgba::new(0, 0, 0, 0.5)));
+
+        let mut rect = Rect::new(0, 0, 10, 10);
+        rect.set_attr(Attr::from("filter"), &format!("url(#{id})"));
+        svg.children_mut().unwrap().push(ChildKind::Element(rect.into()));
+
+        let expected = concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg"><defs><filter id="filter1">"#,
+            r#"<feGaussianBlur in="SourceAlpha" stdDeviation="1.5" result="result1"/>"#,
+            r#"<feOffset in="result1" dx="2" dy="2" result="result2"/>"#,
+            r#"<feFlood result="result3" flood-color="rgba(0,0,0,0.5)"/>"#,
+            r#"<feComposite in="result3" in2="result2" operator="in" result="result4"/>"#,
+            r#"<feMerge><feMergeNode in="result4"/><feMergeNode in="SourceGraphic"/></feMerge>"#,
+            r#"</filter></defs><rect x="0" y="0" width="10" height="10" filter="url(#filter1)"/></svg>"#,
+        );
+
+        assert_eq!(svg.to_string(), expected);
+
+        let glow = Filter::glow(4.0, Color::named("gold").unwrap()).to_string();
+        assert!(glow.contains(r#"<feComposite in="result2" in2="result1" operator="in" result="result3"/>"#));
+    }
+}
@@ -0,0 +1,165 @@
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+
+use crate::{attr::{impl_attr_accessors, length::Length, transform::Matrix, LazyAttrMap}, bbox::{local_matrix, viewport_bbox, BBox, BoundingBox}, error::Error, parser::check_markup, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, ReadXml, TagName, WriteXml};
+
+/// Foreign object element (`<foreignObject>`)
+///
+/// The content, e.g. XHTML, is kept as raw markup and written verbatim.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/foreignObject).
+pub struct ForeignObject {
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+
+    content: Option<String>,
+
+    attr: LazyAttrMap,
+}
+
+impl Default for ForeignObject {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            content: None,
+            attr: None,
+        }
+    }
+}
+
+impl ForeignObject {
+    #[inline]
+    pub fn new<X, Y, W, H>(x: X, y: Y, width: W, height: H) -> Self
+    where
+        X: Into<Length>,
+        Y: Into<Length>,
+        W: Into<Length>,
+        H: Into<Length>,
+    {
+        Self {
+            x: Some(x.into()),
+            y: Some(y.into()),
+            width: Some(width.into()),
+            height: Some(height.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Access the raw markup content.
+    #[inline]
+    pub fn content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+
+    /// Set the raw markup content, which is not escaped.
+    ///
+    /// Returns an error if the markup is not well-formed.
+    pub fn set_content(&mut self, value: Option<&str>) -> Result<&mut Self, Error> {
+        if let Some(value) = value {
+            check_markup(value)?;
+        }
+
+        self.content = value.map(str::to_string);
+        Ok(self)
+    }
+
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+}
+
+impl_element!(ForeignObject?, "foreignObject");
+impl_attr_accessors!(ForeignObject);
+
+impl WriteXml for ForeignObject {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, _: usize) -> Result<(), Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        options.attr_order.sort(&mut bs)?;
+
+        let Some(content) = self.content.as_ref() else {
+            return writer.write_event(Event::Empty(bs))
+                .map_err(Error::XmlWriterError);
+        };
+
+        // Line breaks would alter the markup, so the content stays inline
+        writer.write_event(Event::Start(bs))
+            .map_err(Error::XmlWriterError)?;
+
+        writer.write_event(Event::Text(BytesText::from_escaped(content.as_str())))
+            .map_err(Error::XmlWriterError)?;
+
+        writer.write_event(Event::End(BytesEnd::new(tag)))
+            .map_err(Error::XmlWriterError)
+    }
+}
+
+impl ReadXml for ForeignObject {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for ForeignObject {
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        viewport_bbox(&local_matrix(self, matrix)?, self.x, self.y, self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn raw_content() {
+        let mut object = ForeignObject::new(0, 0, 100, 50);
+        object.set_content(Some(r#"<div xmlns="http://www.w3.org/1999/xhtml">Fish &amp; <b>chips</b></div>"#)).unwrap();
+
+        let expected = r#"<foreignObject x="0" y="0" width="100" height="50"><div xmlns="http://www.w3.org/1999/xhtml">Fish &amp; <b>chips</b></div></foreignObject>"#;
+
+        assert_eq!(object.to_string(), expected);
+
+        let svg = format!(r#"<svg xmlns="http://www.w3.org/2000/svg">{expected}</svg>"#);
+        assert_eq!(crate::parser::parse(&svg).unwrap().to_string(), svg);
+    }
+
+    #[test]
+    fn reject_malformed_content() {
+        let mut object = ForeignObject::new(0, 0, 100, 50);
+
+        assert!(matches!(object.set_content(Some("<p>Fish & chips</p>")), Err(Error::XmlReaderError(_))));
+        assert!(matches!(object.set_content(Some("<p>Fish</b>")), Err(Error::XmlReaderError(_))));
+        assert!(matches!(object.set_content(Some("</p>")), Err(Error::XmlReaderError(_))));
+        assert!(matches!(object.set_content(Some(r#"<p class="a&b"/>"#)), Err(Error::XmlReaderError(_))));
+        assert!(matches!(object.set_content(Some("<p><b>Fish</b>")), Err(Error::UnexpectedEof)));
+        assert_eq!(object.content(), None);
+
+        object.set_content(Some("Fish &lt; chips")).unwrap();
+        assert_eq!(object.to_string(), r#"<foreignObject x="0" y="0" width="100" height="50">Fish &lt; chips</foreignObject>"#);
+    }
+}
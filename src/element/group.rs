@@ -1,8 +1,8 @@
 use quick_xml::events::BytesStart;
 
-use crate::{attr::{impl_attr_accessors, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr};
+use crate::{attr::{impl_attr_accessors, transform::Matrix, LazyAttrMap}, bbox::{children_bbox, local_matrix, BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
 
-use super::{impl_element, ChildKind, LazyChildList, TagName, WriteXml};
+use super::{impl_element, ChildKind, LazyChildList, ReadXml, TagName, WriteXml};
 
 /// SVG group element (<g>)
 ///
@@ -14,7 +14,6 @@ pub struct Group {
 
 impl Default for Group {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self {
             attr: None,
@@ -43,13 +42,27 @@ impl_element!(Group, "g");
 impl_attr_accessors!(Group);
 
 impl WriteXml for Group {
-    fn write_xml(&self, writer: &mut quick_xml::Writer<std::io::Cursor<Vec<u8>>>) -> Result<(), crate::error::Error> {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
         let tag = Self::tag_name();
 
         let mut bs = BytesStart::new(tag);
 
-        push_attr!(map: self.attr, bs);
+        push_attr!(map: self.attr, bs, options);
 
-        convert_into_xml(writer, bs, self.children(), tag)
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Group {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        pull_attr!(map: self, key, value);
+
+        Ok(())
+    }
+}
+
+impl BBox for Group {
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        children_bbox(self.children(), &local_matrix(self, matrix)?)
     }
 }
@@ -0,0 +1,233 @@
+use quick_xml::events::BytesStart;
+
+#[cfg(feature = "crossorigin")]
+use crate::attr::cross_origin::CrossOrigin;
+use crate::{attr::{decoding::Decoding, impl_attr_accessors, length::Length, transform::Matrix, view_box::PreserveAspectRatio, LazyAttrMap}, bbox::{local_matrix, viewport_bbox, BBox, BoundingBox}, element::{convert_into_xml, Children}, error::Error, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encode bytes as padded base64.
+fn encode_base64(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let bits = chunk.iter()
+            .enumerate()
+            .fold(0u32, |bits, (i, &byte)| bits | (byte as u32) << (16 - 8 * i));
+
+        // A chunk of n bytes fills n + 1 digits
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_ALPHABET[(bits >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+/// Media type of an image file by its lowercase extension.
+fn media_type(extension: &str) -> Option<&'static str> {
+    Some(match extension {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "bmp" => "image/bmp",
+        "ico" => "image/x-icon",
+        _ => return None,
+    })
+}
+
+/// Image element (`<image>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/image).
+pub struct Image {
+    href: Option<String>,
+    xlink: bool,
+
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+
+    preserve_aspect_ratio: Option<PreserveAspectRatio>,
+    #[cfg(feature = "crossorigin")]
+    crossorigin: Option<CrossOrigin>,
+    decoding: Option<Decoding>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Image {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            href: None,
+            xlink: false,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            preserve_aspect_ratio: None,
+            #[cfg(feature = "crossorigin")]
+            crossorigin: None,
+            decoding: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Image {
+    #[inline]
+    pub fn new<T>(href: &T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            href: Some(href.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Embed image data of the given media type, e.g. `image/png`,
+    /// as a base64 `data:` URI.
+    pub fn from_data(media_type: &str, data: &[u8]) -> Self {
+        Self::new(&format!("data:{media_type};base64,{}", encode_base64(data)))
+    }
+
+    /// Embed a local image file as a base64 `data:` URI, with the media type
+    /// guessed from the file extension.
+    pub fn embed<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let path = path.as_ref();
+
+        let extension = path.extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        let media_type = media_type(&extension).ok_or(Error::UnknownMediaType(extension))?;
+        let data = std::fs::read(path).map_err(Error::IoError)?;
+
+        Ok(Self::from_data(media_type, &data))
+    }
+
+    impl_accessor!(href -> href, set_href);
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(primitive -> preserve_aspect_ratio, set_preserve_aspect_ratio, PreserveAspectRatio, "preserveAspectRatio");
+    #[cfg(feature = "crossorigin")]
+    impl_accessor!(primitive -> crossorigin, set_crossorigin, CrossOrigin, "crossorigin");
+    impl_accessor!(primitive -> decoding, set_decoding, Decoding, "decoding");
+}
+
+impl_element!(Image, "image");
+impl_attr_accessors!(Image);
+
+impl WriteXml for Image {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(href: self, bs);
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.preserve_aspect_ratio, bs, "preserveAspectRatio" <- ToString);
+        #[cfg(feature = "crossorigin")]
+        push_attr!(self.crossorigin, bs, "crossorigin" <- ToString);
+        push_attr!(self.decoding, bs, "decoding" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Image {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "href" | "xlink:href" => pull_attr!(href: self, key, value),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "preserveAspectRatio" => pull_attr!(self.preserve_aspect_ratio, value <- FromStr),
+            #[cfg(feature = "crossorigin")]
+            "crossorigin" => pull_attr!(self.crossorigin, value <- FromStr),
+            "decoding" => pull_attr!(self.decoding, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Image {
+    /// Images without an explicit size have no known bounding box.
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        viewport_bbox(&local_matrix(self, matrix)?, self.x, self.y, self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn base64() {
+        let cases = [("", ""), ("f", "Zg=="), ("fo", "Zm8="), ("foo", "Zm9v"), ("foobar", "Zm9vYmFy")];
+
+        for (data, expected) in cases {
+            assert_eq!(encode_base64(data.as_bytes()), expected);
+        }
+
+        assert_eq!(encode_base64(&[0xfb, 0xff]), "+/8=");
+    }
+
+    #[test]
+    fn embed_file() {
+        let path = std::env::temp_dir().join(format!("svgize-embed-{}.PNG", std::process::id()));
+        std::fs::write(&path, b"\x89PNG").unwrap();
+
+        let mut image = Image::embed(&path).unwrap();
+        image.set_width(Some(16)).set_height(Some(16));
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(image.to_string(), r#"<image href="data:image/png;base64,iVBORw==" width="16" height="16"/>"#);
+        assert_eq!(image.bbox().map(|bbox| (bbox.width(), bbox.height())), Some((16.0, 16.0)));
+        assert!(matches!(Image::embed("notes.txt"), Err(Error::UnknownMediaType(ext)) if ext == "txt"));
+    }
+
+    #[cfg(feature = "crossorigin")]
+    #[test]
+    fn cors_settings() {
+        let expected = r#"<image xlink:href="cat.png" crossorigin="use-credentials" decoding="async"/>"#;
+
+        let el = crate::parser::parse(r#"<image xlink:href="cat.png" decoding="async" crossorigin="use-credentials"/>"#).unwrap();
+        assert_eq!(el.to_string(), expected);
+
+        let mut image = Image::new(&"cat.png");
+        image.set_crossorigin(Some(CrossOrigin::UseCredentials)).set_decoding(Some(Decoding::Async));
+        assert_eq!(image.to_string(), expected.replace("xlink:href", "href"));
+
+        image.set_xlink(true);
+        assert_eq!(image.to_string(), expected);
+    }
+}
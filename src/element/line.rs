@@ -0,0 +1,134 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, length::Length, transform::Matrix, LazyAttrMap}, bbox::{local_matrix, BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions, Point};
+
+use super::{impl_accessor, impl_element, ChildList, ReadXml, TagName, WriteXml};
+
+/// Line element (`<line>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/line).
+pub struct Line {
+    x1: Option<Length>,
+    y1: Option<Length>,
+    x2: Option<Length>,
+    y2: Option<Length>,
+    path_length: Option<f32>,
+
+    attr: LazyAttrMap,
+
+    children: Option<ChildList>,
+}
+
+impl Default for Line {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            x1: None,
+            y1: None,
+            x2: None,
+            y2: None,
+            path_length: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Line {
+    /// Create a new line
+    ///
+    /// ## Parameters
+    ///
+    /// - from: For `x1` and `y1` attributes
+    /// - to: For `x2` and `y2` attributes
+    pub fn new<TX1, TY1, TX2, TY2>(from: (TX1, TY1), to: (TX2, TY2)) -> Self
+    where
+        TX1: Into<Length>,
+        TY1: Into<Length>,
+        TX2: Into<Length>,
+        TY2: Into<Length>,
+    {
+        let mut l = Line::default();
+
+        l.set_x1(Some(from.0));
+        l.set_y1(Some(from.1));
+
+        l.set_x2(Some(to.0));
+        l.set_y2(Some(to.1));
+
+        l
+    }
+
+    impl_accessor!(length -> x1, set_x1, "x1");
+    impl_accessor!(length -> y1, set_y1, "y1");
+    impl_accessor!(length -> x2, set_x2, "x2");
+    impl_accessor!(length -> y2, set_y2, "y2");
+    impl_accessor!(primitive -> path_length, set_path_length, f32, "pathLength");
+}
+
+impl_element!(Line, "line");
+impl_attr_accessors!(Line);
+
+impl WriteXml for Line {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.x1, bs, "x1" <- Format(options));
+        push_attr!(self.y1, bs, "y1" <- Format(options));
+        push_attr!(self.x2, bs, "x2" <- Format(options));
+        push_attr!(self.y2, bs, "y2" <- Format(options));
+        push_attr!(self.path_length, bs, "pathLength" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Line {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "x1" => pull_attr!(self.x1, value <- FromStr | map: self, key),
+            "y1" => pull_attr!(self.y1, value <- FromStr | map: self, key),
+            "x2" => pull_attr!(self.x2, value <- FromStr | map: self, key),
+            "y2" => pull_attr!(self.y2, value <- FromStr | map: self, key),
+            "pathLength" => pull_attr!(self.path_length, value <- prim),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Line {
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        let matrix = local_matrix(self, matrix)?;
+
+        let from = Point(
+            self.x1.unwrap_or_default().to_absolute_user_units()?,
+            self.y1.unwrap_or_default().to_absolute_user_units()?,
+        );
+        let to = Point(
+            self.x2.unwrap_or_default().to_absolute_user_units()?,
+            self.y2.unwrap_or_default().to_absolute_user_units()?,
+        );
+
+        Some(BoundingBox::new(matrix.apply(from), matrix.apply(to)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn write_and_parse() {
+        let line = Line::new((0, 0), (1.5, 1));
+        let expected = r#"<line x1="0" y1="0" x2="1.5" y2="1"/>"#;
+
+        assert_eq!(line.to_string(), expected);
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+    }
+}
@@ -0,0 +1,172 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{color::Color, impl_attr_accessors, length::Length, paint::Paint, spread_method::SpreadMethod, transform::{Matrix, Transform}, units::Units, AccessAttr, Attr, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, stop::stop_list, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Linear gradient element (`<linearGradient>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/linearGradient).
+pub struct LinearGradient {
+    x1: Option<Length>,
+    y1: Option<Length>,
+    x2: Option<Length>,
+    y2: Option<Length>,
+
+    gradient_units: Option<Units>,
+    gradient_transform: Option<Transform>,
+    spread_method: Option<SpreadMethod>,
+    href: Option<String>,
+    xlink: bool,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for LinearGradient {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            x1: None,
+            y1: None,
+            x2: None,
+            y2: None,
+            gradient_units: None,
+            gradient_transform: None,
+            spread_method: None,
+            href: None,
+            xlink: false,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl LinearGradient {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a gradient with `<stop>` children from `(offset, color)` pairs,
+    /// where offsets range from `0.0` to `1.0`.
+    pub fn with_stops<I, C>(stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: Into<Color>,
+    {
+        Self {
+            children: Some(stop_list(stops)),
+            ..Default::default()
+        }
+    }
+
+    /// Paint referencing the gradient by its `id`, or `None` without `id`.
+    pub fn paint(&self) -> Option<Paint> {
+        Some(Paint::url(self.attr(&Attr::from("id"))?))
+    }
+
+    impl_accessor!(length -> x1, set_x1, "x1");
+    impl_accessor!(length -> y1, set_y1, "y1");
+    impl_accessor!(length -> x2, set_x2, "x2");
+    impl_accessor!(length -> y2, set_y2, "y2");
+    impl_accessor!(primitive -> gradient_units, set_gradient_units, Units, "gradientUnits");
+    impl_accessor!(ref:clone_setter -> gradient_transform, set_gradient_transform, "gradientTransform", Transform);
+    impl_accessor!(primitive -> spread_method, set_spread_method, SpreadMethod, "spreadMethod");
+    impl_accessor!(href -> href, set_href);
+}
+
+impl_element!(LinearGradient, "linearGradient");
+impl_attr_accessors!(LinearGradient);
+
+impl WriteXml for LinearGradient {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.x1, bs, "x1" <- Format(options));
+        push_attr!(self.y1, bs, "y1" <- Format(options));
+        push_attr!(self.x2, bs, "x2" <- Format(options));
+        push_attr!(self.y2, bs, "y2" <- Format(options));
+        push_attr!(self.gradient_units, bs, "gradientUnits" <- ToString);
+        push_attr!(self.gradient_transform, bs, "gradientTransform" <- ToString);
+        push_attr!(self.spread_method, bs, "spreadMethod" <- ToString);
+        push_attr!(href: self, bs);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for LinearGradient {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "x1" => pull_attr!(self.x1, value <- FromStr | map: self, key),
+            "y1" => pull_attr!(self.y1, value <- FromStr | map: self, key),
+            "x2" => pull_attr!(self.x2, value <- FromStr | map: self, key),
+            "y2" => pull_attr!(self.y2, value <- FromStr | map: self, key),
+            "gradientUnits" => pull_attr!(self.gradient_units, value <- FromStr),
+            "gradientTransform" => pull_attr!(self.gradient_transform, value <- FromStr),
+            "spreadMethod" => pull_attr!(self.spread_method, value <- FromStr),
+            "href" | "xlink:href" => pull_attr!(href: self, key, value),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for LinearGradient {
+    /// Paint servers are not rendered.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{attr::color::Rgba, element::{Children, ChildKind, Rect, Svg}};
+
+    use super::*;
+
+    #[test]
+    fn build_and_reference() {
+        let mut gradient = LinearGradient::with_stops([(0.0, Color::rgb(255, 0, 0)), (1.0, Rgba::new(0, 0, 255, 0.5).into())]);
+        gradient.set_spread_method(Some(SpreadMethod::Reflect));
+
+        assert!(gradient.paint().is_none());
+
+        let mut svg = Svg::new();
+        let paint = Paint::url(&svg.define_id(gradient));
+
+        let mut rect = Rect::new(0, 0, 10, 10);
+        rect.set_attr(Attr::from("fill"), &paint.to_string());
+        svg.children_mut().unwrap().push(ChildKind::Element(rect.into()));
+
+        let expected = concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg"><defs><linearGradient spreadMethod="reflect" id="linearGradient1">"#,
+            r#"<stop offset="0" stop-color="red"/><stop offset="1" stop-color="rgba(0,0,255,0.5)"/></linearGradient></defs>"#,
+            r##"<rect x="0" y="0" width="10" height="10" fill="url(#linearGradient1)"/></svg>"##,
+        );
+
+        assert_eq!(svg.to_string(), expected);
+        assert_eq!(expected.parse::<Svg>().unwrap().to_string(), expected);
+    }
+
+    #[test]
+    fn keep_xlink_href() {
+        let src = concat!(
+            r#"<defs><linearGradient id="a"><stop offset="0" stop-color="red"/></linearGradient>"#,
+            r##"<linearGradient xlink:href="#a" id="b"/><radialGradient xlink:href="#a" id="c"/><radialGradient href="#a" id="d"/></defs>"##,
+        );
+
+        assert_eq!(crate::parser::parse(src).unwrap().to_string(), src);
+
+        let mut gradient = LinearGradient::new();
+        gradient.set_href(Some(&"#a")).set_xlink(true);
+        assert_eq!(gradient.to_string(), r##"<linearGradient xlink:href="#a"/>"##);
+    }
+}
@@ -0,0 +1,239 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, length::Length, marker::{MarkerUnits, Orient}, transform::Matrix, view_box::{PreserveAspectRatio, ViewBox}, AccessAttr, Attr, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, ChildKind, Circle, ElementKind, LazyChildList, Path, ReadXml, Rect, TagName, WriteXml};
+
+/// Marker element (`<marker>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/marker).
+pub struct Marker {
+    marker_width: Option<Length>,
+    marker_height: Option<Length>,
+    ref_x: Option<Length>,
+    ref_y: Option<Length>,
+    orient: Option<Orient>,
+    marker_units: Option<MarkerUnits>,
+    view_box: Option<ViewBox>,
+    preserve_aspect_ratio: Option<PreserveAspectRatio>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Marker {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            marker_width: None,
+            marker_height: None,
+            ref_x: None,
+            ref_y: None,
+            orient: None,
+            marker_units: None,
+            view_box: None,
+            preserve_aspect_ratio: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Marker {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    impl_accessor!(length -> marker_width, set_marker_width, "markerWidth");
+    impl_accessor!(length -> marker_height, set_marker_height, "markerHeight");
+    impl_accessor!(length -> ref_x, set_ref_x, "refX");
+    impl_accessor!(length -> ref_y, set_ref_y, "refY");
+    impl_accessor!(primitive:raw -> orient, set_orient, Orient, "orient");
+    impl_accessor!(primitive:raw -> marker_units, set_marker_units, MarkerUnits, "markerUnits");
+    impl_accessor!(primitive:raw -> view_box, set_view_box, ViewBox, "viewBox");
+    impl_accessor!(primitive:raw -> preserve_aspect_ratio, set_preserve_aspect_ratio, PreserveAspectRatio, "preserveAspectRatio");
+}
+
+/// Ready-made markers
+///
+/// Markers are drawn in a `10` by `10` view box scaled to six times
+/// the stroke width, and painted with the stroke of the referencing element
+/// through `context-stroke`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarkerPreset {
+    /// Filled triangle pointing along the path
+    Arrow,
+    /// Chevron pointing along the path
+    OpenArrow,
+    /// Filled circle
+    Dot,
+    /// Filled square
+    Square,
+    /// Bar across the path
+    Bar,
+}
+
+impl MarkerPreset {
+    /// Default `id` of the marker, shared by every use of the preset.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Arrow => "marker-arrow",
+            Self::OpenArrow => "marker-open-arrow",
+            Self::Dot => "marker-dot",
+            Self::Square => "marker-square",
+            Self::Bar => "marker-bar",
+        }
+    }
+
+    /// Build the marker, with its `id` set to [`MarkerPreset::id`].
+    pub fn marker(&self) -> Marker {
+        let mut marker = Marker::new();
+
+        marker.set_view_box(Some(ViewBox::new(0.0, 0.0, 10.0, 10.0)))
+            .set_marker_width(Some(6))
+            .set_marker_height(Some(6))
+            .set_ref_y(Some(5));
+
+        let shape: ElementKind = match self {
+            Self::Arrow | Self::OpenArrow => {
+                marker.set_ref_x(Some(10)).set_orient(Some(Orient::AutoStartReverse));
+
+                let mut path = Path::new();
+                path.data_mut().move_to(crate::Point(0.0, 0.0)).draw_line(crate::Point(10.0, 5.0)).draw_line(crate::Point(0.0, 10.0));
+
+                if *self == Self::Arrow {
+                    path.data_mut().close();
+                    path.set_attr(Attr::from("fill"), "context-stroke");
+                } else {
+                    path.set_attr(Attr::from("fill"), "none");
+                    path.set_attr(Attr::from("stroke"), "context-stroke");
+                    path.set_attr(Attr::from("stroke-width"), "2");
+                }
+
+                path.into()
+            }
+            Self::Dot => {
+                marker.set_ref_x(Some(5));
+
+                let mut circle = Circle::new((5, 5), 5);
+                circle.set_attr(Attr::from("fill"), "context-stroke");
+                circle.into()
+            }
+            Self::Square | Self::Bar => {
+                marker.set_ref_x(Some(5)).set_orient(Some(Orient::Auto));
+
+                let mut rect = match self {
+                    Self::Square => Rect::new(0, 0, 10, 10),
+                    _ => Rect::new(4, 0, 2, 10),
+                };
+                rect.set_attr(Attr::from("fill"), "context-stroke");
+                rect.into()
+            }
+        };
+
+        marker.children = Some(vec![ChildKind::Element(shape)]);
+        marker.set_attr(Attr::from("id"), self.id());
+
+        marker
+    }
+}
+
+impl From<MarkerPreset> for Marker {
+    #[inline]
+    fn from(value: MarkerPreset) -> Self {
+        value.marker()
+    }
+}
+
+impl_element!(Marker, "marker");
+impl_attr_accessors!(Marker);
+
+impl WriteXml for Marker {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.view_box, bs, "viewBox" <- Format(options));
+        push_attr!(self.preserve_aspect_ratio, bs, "preserveAspectRatio" <- ToString);
+        push_attr!(self.marker_width, bs, "markerWidth" <- Format(options));
+        push_attr!(self.marker_height, bs, "markerHeight" <- Format(options));
+        push_attr!(self.ref_x, bs, "refX" <- Format(options));
+        push_attr!(self.ref_y, bs, "refY" <- Format(options));
+        push_attr!(self.orient, bs, "orient" <- ToString);
+        push_attr!(self.marker_units, bs, "markerUnits" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Marker {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "viewBox" => pull_attr!(self.view_box, value <- FromStr | map: self, key),
+            "preserveAspectRatio" => pull_attr!(self.preserve_aspect_ratio, value <- FromStr | map: self, key),
+            "markerWidth" => pull_attr!(self.marker_width, value <- FromStr | map: self, key),
+            "markerHeight" => pull_attr!(self.marker_height, value <- FromStr | map: self, key),
+            "refX" => pull_attr!(self.ref_x, value <- FromStr | map: self, key),
+            "refY" => pull_attr!(self.ref_y, value <- FromStr | map: self, key),
+            "orient" => pull_attr!(self.orient, value <- FromStr | map: self, key),
+            "markerUnits" => pull_attr!(self.marker_units, value <- FromStr | map: self, key),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Marker {
+    /// Markers are only rendered on the vertices of other shapes.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{attr::marker::MarkerPosition, element::{Line, Svg}};
+
+    use super::*;
+
+    #[test]
+    fn parse_orient() {
+        assert_eq!("auto-start-reverse".parse::<Orient>().unwrap(), Orient::AutoStartReverse);
+        assert_eq!("0.5turn".parse::<Orient>().unwrap(), Orient::Angle(180.0));
+        assert_eq!(Orient::Angle(45.0).to_string(), "45");
+        assert!("sideways".parse::<Orient>().is_err());
+    }
+
+    #[test]
+    fn attach_presets() {
+        let mut svg = Svg::new();
+
+        let mut line = Line::new((0, 0), (10, 0));
+        let mut path = Path::from_data("M 0,0 L 5,5".parse().unwrap());
+
+        assert_eq!(svg.attach_marker(&mut line, MarkerPosition::End, MarkerPreset::Arrow), "marker-arrow");
+        svg.attach_marker(&mut path, MarkerPosition::Start, MarkerPreset::Arrow);
+        svg.attach_marker(&mut path, MarkerPosition::End, MarkerPreset::Dot);
+
+        assert_eq!(line.to_string(), r##"<line x1="0" y1="0" x2="10" y2="0" marker-end="url(#marker-arrow)"/>"##);
+        assert_eq!(path.to_string(), r##"<path d="M 0,0 L 5,5" marker-start="url(#marker-arrow)" marker-end="url(#marker-dot)"/>"##);
+
+        assert_eq!(
+            svg.to_string(),
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg"><defs>"#,
+                r#"<marker viewBox="0 0 10 10" markerWidth="6" markerHeight="6" refX="10" refY="5" orient="auto-start-reverse" id="marker-arrow">"#,
+                r#"<path d="M 0,0 L 10,5 L 0,10 Z" fill="context-stroke"/></marker>"#,
+                r#"<marker viewBox="0 0 10 10" markerWidth="6" markerHeight="6" refX="5" refY="5" id="marker-dot">"#,
+                r#"<circle cx="5" cy="5" r="5" fill="context-stroke"/></marker>"#,
+                r#"</defs></svg>"#,
+            ),
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, length::Length, transform::Matrix, units::Units, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Mask element (`<mask>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/mask).
+pub struct Mask {
+    mask_units: Option<Units>,
+    mask_content_units: Option<Units>,
+
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Mask {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            mask_units: None,
+            mask_content_units: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Mask {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    impl_accessor!(primitive -> mask_units, set_mask_units, Units, "maskUnits");
+    impl_accessor!(primitive -> mask_content_units, set_mask_content_units, Units, "maskContentUnits");
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+}
+
+impl_element!(Mask, "mask");
+impl_attr_accessors!(Mask);
+
+impl WriteXml for Mask {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.mask_units, bs, "maskUnits" <- ToString);
+        push_attr!(self.mask_content_units, bs, "maskContentUnits" <- ToString);
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Mask {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "maskUnits" => pull_attr!(self.mask_units, value <- FromStr),
+            "maskContentUnits" => pull_attr!(self.mask_content_units, value <- FromStr),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Mask {
+    /// Masks are not rendered.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
@@ -0,0 +1,116 @@
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+
+use crate::{attr::{impl_attr_accessors, transform::Matrix, LazyAttrMap}, bbox::{BBox, BoundingBox}, error::Error, parser::check_markup, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_element, ReadXml, TagName, WriteXml};
+
+/// Metadata element (`<metadata>`)
+///
+/// The content, e.g. RDF, is kept as raw markup and written verbatim.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/metadata).
+pub struct Metadata {
+    content: Option<String>,
+
+    attr: LazyAttrMap,
+}
+
+impl Default for Metadata {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            content: None,
+            attr: None,
+        }
+    }
+}
+
+impl Metadata {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Access the raw markup content.
+    #[inline]
+    pub fn content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+
+    /// Set the raw markup content, which is not escaped.
+    ///
+    /// Returns an error if the markup is not well-formed.
+    pub fn set_content(&mut self, value: Option<&str>) -> Result<&mut Self, Error> {
+        if let Some(value) = value {
+            check_markup(value)?;
+        }
+
+        self.content = value.map(str::to_string);
+        Ok(self)
+    }
+}
+
+impl_element!(Metadata?, "metadata");
+impl_attr_accessors!(Metadata);
+
+impl WriteXml for Metadata {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, _: usize) -> Result<(), Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(map: self.attr, bs, options);
+
+        options.attr_order.sort(&mut bs)?;
+
+        let Some(content) = self.content.as_ref() else {
+            return writer.write_event(Event::Empty(bs))
+                .map_err(Error::XmlWriterError);
+        };
+
+        // Line breaks would alter the markup, so the content stays inline
+        writer.write_event(Event::Start(bs))
+            .map_err(Error::XmlWriterError)?;
+
+        writer.write_event(Event::Text(BytesText::from_escaped(content.as_str())))
+            .map_err(Error::XmlWriterError)?;
+
+        writer.write_event(Event::End(BytesEnd::new(tag)))
+            .map_err(Error::XmlWriterError)
+    }
+}
+
+impl ReadXml for Metadata {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        pull_attr!(map: self, key, value);
+
+        Ok(())
+    }
+}
+
+impl BBox for Metadata {
+    /// Metadata is not rendered.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn raw_content() {
+        let content = r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"><rdf:Description about="Fish &amp; chips"/></rdf:RDF>"#;
+        let expected = format!("<metadata>{content}</metadata>");
+
+        let mut metadata = Metadata::new();
+        metadata.set_content(Some(content)).unwrap();
+
+        assert_eq!(metadata.to_string(), expected);
+
+        let svg = format!(r#"<svg xmlns="http://www.w3.org/2000/svg">{expected}<rect width="1" height="1"/></svg>"#);
+        assert_eq!(crate::parser::parse(&svg).unwrap().to_string(), svg);
+    }
+}
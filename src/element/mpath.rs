@@ -0,0 +1,80 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, LazyAttrMap}, bbox::impl_no_bbox, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Motion path element (`<mpath>`), referencing the `<path>` followed by
+/// its parent `<animateMotion>`
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/mpath).
+pub struct MPath {
+    href: Option<String>,
+    xlink: bool,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for MPath {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            href: None,
+            xlink: false,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl MPath {
+    #[inline]
+    pub fn new<T>(href: &T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            href: Some(href.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Reference a `<path>` in the same document by its `id`.
+    #[inline]
+    pub fn referencing(id: &str) -> Self {
+        Self::new(&format!("#{id}"))
+    }
+
+    impl_accessor!(href -> href, set_href);
+}
+
+impl_element!(MPath, "mpath");
+impl_attr_accessors!(MPath);
+
+impl WriteXml for MPath {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(href: self, bs);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for MPath {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "href" | "xlink:href" => pull_attr!(href: self, key, value),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl_no_bbox!(MPath, "Motion paths are not rendered.");
@@ -1,14 +1,18 @@
+use std::fmt::Display;
+
 use quick_xml::events::BytesStart;
 
-use crate::{attr::{impl_attr_accessors, LazyAttrMap}, element::{convert_into_xml, TagName}, push_attr, Point};
+use crate::{attr::{impl_attr_accessors, transform::Matrix, LazyAttrMap}, bbox::{local_matrix, path_bbox, BBox, BoundingBox}, element::{convert_into_xml, TagName}, push_attr, pull_attr, serialize::{join_compact, FormatValue, SerializeOptions}, Point};
+
+use super::{impl_accessor, impl_element, ReadXml, WriteXml};
 
-use super::{impl_accessor, impl_element, WriteXml};
+mod parse;
 
 /// Path element (`<path>`)
 ///
 /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/path).
 pub struct Path {
-    data: Option<String>,
+    data: Option<PathData>,
     path_length: Option<f32>,
     
     attr: LazyAttrMap,
@@ -16,7 +20,6 @@ pub struct Path {
 
 impl Default for Path {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self {
             data: None,
@@ -32,32 +35,60 @@ impl Path {
         Self::default()
     }
 
-    pub fn from_data<T>(data: T) -> Self
-    where
-        T: ToString,
-    {
+    /// Create a path from structured path data.
+    pub fn from_data(data: PathData) -> Self {
         let mut p = Self::default();
-        p.set_data(Some(&data));
+        p.set_data(data);
 
         p
     }
 
-    impl_accessor!(string* -> data, set_data, "d");
+    impl_accessor!(ref:move_setter -> data, set_data, "d", PathData);
+
+    /// Access mutable reference of `d`, creating empty path data if absent.
+    ///
+    /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/d).
+    pub fn data_mut(&mut self) -> &mut PathData {
+        self.data.get_or_insert_with(PathData::new)
+    }
+
+    /// Remove `d` from the path and return it.
+    pub fn take_data(&mut self) -> Option<PathData> {
+        self.data.take()
+    }
     impl_accessor!(primitive -> path_length, set_path_length, f32, "pathLength");
 }
 
 impl WriteXml for Path {
-    fn write_xml(&self, writer: &mut quick_xml::Writer<std::io::Cursor<Vec<u8>>>) -> Result<(), crate::error::Error> {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
         let tag = Self::tag_name();
             
         let mut bs = BytesStart::new(tag);
 
-        push_attr!(self.data, bs, "d" <- String);
-        push_attr!(self.path_length, bs, "pathLength" <- prim);
+        push_attr!(self.data, bs, "d" <- Format(options));
+        push_attr!(self.path_length, bs, "pathLength" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, None, tag, options, depth)
+    }
+}
+
+impl ReadXml for Path {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "d" => pull_attr!(self.data, value <- FromStr),
+            "pathLength" => pull_attr!(self.path_length, value <- prim),
+            _ => pull_attr!(map: self, key, value),
+        }
 
-        push_attr!(map: self.attr, bs);
+        Ok(())
+    }
+}
 
-        convert_into_xml(writer, bs, None, tag)
+impl BBox for Path {
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        path_bbox(self.data.as_ref()?, &local_matrix(self, matrix)?)
     }
 }
 
@@ -65,44 +96,66 @@ impl_element!(Path?, "path");
 
 impl_attr_accessors!(Path);
 
-#[derive(PartialEq)]
-enum PathCommandKind {
+/// Path command enumeration
+///
+/// Each command has an absolute and a relative (`*Rel`) variant.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/d#path_commands).
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathCommandKind {
+    /// `M`
     MoveToAbs(Point),
+    /// `m`
     MoveToRel(Point),
+    /// `L`
     LineAbs(Point),
+    /// `l`
     LineRel(Point),
+    /// `H`
     HorizontalAbs(f32),
+    /// `h`
     HorizontalRel(f32),
+    /// `V`
     VerticalAbs(f32),
+    /// `v`
     VerticalRel(f32),
+    /// `C`
     CubicBezierAbs {
         con_start: Point,
         con_end: Point,
         to: Point,
     },
+    /// `c`
     CubicBezierRel {
         d_con_start: Point,
         d_con_end: Point,
         d_to: Point,
     },
+    /// `S`
     SmoothCubicBezierAbs {
         con_end: Point,
         to: Point,
     },
+    /// `s`
     SmoothCubicBezierRel {
         d_con_end: Point,
         d_to: Point,
     },
+    /// `Q`
     QuadraticBezierAbs {
         con: Point,
         to: Point,
     },
+    /// `q`
     QuadraticBezierRel {
         d_con: Point,
         d_to: Point,
     },
+    /// `T`
     SmoothQuadraticBezierAbs(Point),
+    /// `t`
     SmoothQuadraticBezierRel(Point),
+    /// `A`
     EllipticalArcAbs {
         radius_x: f32,
         radius_y: f32,
@@ -111,6 +164,7 @@ enum PathCommandKind {
         sf: SweepFlag,
         shift: Point,
     },
+    /// `a`
     EllipticalArcRel {
         radius_x: f32,
         radius_y: f32,
@@ -119,26 +173,51 @@ enum PathCommandKind {
         sf: SweepFlag,
         d_shift: Point,
     },
+    /// `Z`
     Close,
 }
 
-#[derive(PartialEq, Clone, Copy)]
+/// `large-arc-flag` of elliptical arc commands
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum LargeArcFlag {
     Small = 0,
     Large,
 }
 
-#[derive(PartialEq, Clone, Copy)]
+/// `sweep-flag` of elliptical arc commands
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum SweepFlag {
     CounterClockwise = 0,
     Clockwise,
 }
 
+/// Structured value of the `d` attribute
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/d).
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct PathData {
     cmds: Vec<PathCommandKind>,
 }
 
 impl PathData {
+    /// Create empty path data.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Access the command list.
+    #[inline]
+    pub fn commands(&self) -> &[PathCommandKind] {
+        &self.cmds
+    }
+
+    /// Append a command.
+    pub fn push(&mut self, cmd: PathCommandKind) -> &mut Self {
+        self.cmds.push(cmd);
+        self
+    }
+
     pub fn move_to(&mut self, point: Point) -> &mut Self {
         self.cmds.push(PathCommandKind::MoveToAbs(point));
         self
@@ -261,6 +340,199 @@ impl PathData {
         self.cmds.push(PathCommandKind::Close);
         self
     }
+
+    /// Convert every command into its absolute variant.
+    pub fn to_absolute(&self) -> PathData {
+        let mut cursor = PathCursor::new();
+
+        self.cmds.iter()
+            .map(|cmd| cursor.advance(cmd))
+            .collect()
+    }
+
+    /// Convert every command into its relative variant.
+    pub fn to_relative(&self) -> PathData {
+        let mut cursor = PathCursor::new();
+
+        self.cmds.iter()
+            .map(|cmd| {
+                let origin = cursor.current();
+                let o = |p: Point| p - origin;
+
+                match cursor.advance(cmd) {
+                    PathCommandKind::MoveToAbs(to) => PathCommandKind::MoveToRel(o(to)),
+                    PathCommandKind::LineAbs(to) => PathCommandKind::LineRel(o(to)),
+                    PathCommandKind::HorizontalAbs(x) => PathCommandKind::HorizontalRel(x - origin.0),
+                    PathCommandKind::VerticalAbs(y) => PathCommandKind::VerticalRel(y - origin.1),
+                    PathCommandKind::CubicBezierAbs { con_start, con_end, to } => PathCommandKind::CubicBezierRel {
+                        d_con_start: o(con_start),
+                        d_con_end: o(con_end),
+                        d_to: o(to),
+                    },
+                    PathCommandKind::SmoothCubicBezierAbs { con_end, to } => PathCommandKind::SmoothCubicBezierRel {
+                        d_con_end: o(con_end),
+                        d_to: o(to),
+                    },
+                    PathCommandKind::QuadraticBezierAbs { con, to } => PathCommandKind::QuadraticBezierRel {
+                        d_con: o(con),
+                        d_to: o(to),
+                    },
+                    PathCommandKind::SmoothQuadraticBezierAbs(to) => PathCommandKind::SmoothQuadraticBezierRel(o(to)),
+                    PathCommandKind::EllipticalArcAbs { radius_x, radius_y, angle, laf, sf, shift } => PathCommandKind::EllipticalArcRel {
+                        radius_x,
+                        radius_y,
+                        angle,
+                        laf,
+                        sf,
+                        d_shift: o(shift),
+                    },
+                    // `advance` only yields absolute commands
+                    cmd => cmd,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Walker over path commands tracking the drawing state
+///
+/// The cursor keeps the current point, the start of the current subpath
+/// and the control points reflected by `S`/`s` and `T`/`t` commands.
+#[derive(Debug, Clone, Default)]
+pub struct PathCursor {
+    current: Point,
+    subpath_start: Point,
+    cubic_control: Option<Point>,
+    quadratic_control: Option<Point>,
+}
+
+impl PathCursor {
+    /// Create a cursor at the origin.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current point.
+    #[inline]
+    pub fn current(&self) -> Point {
+        self.current
+    }
+
+    /// Start point of the current subpath, where `Z` returns to.
+    #[inline]
+    pub fn subpath_start(&self) -> Point {
+        self.subpath_start
+    }
+
+    /// First control point of a smooth cubic Bézier (`S`) drawn next.
+    ///
+    /// This is the reflection of the previous cubic control point,
+    /// or the current point if the previous command is not a cubic Bézier.
+    pub fn reflected_cubic_control(&self) -> Point {
+        self.cubic_control
+            .map(|con| self.current * 2.0 - con)
+            .unwrap_or(self.current)
+    }
+
+    /// Control point of a smooth quadratic Bézier (`T`) drawn next.
+    ///
+    /// This is the reflection of the previous quadratic control point,
+    /// or the current point if the previous command is not a quadratic Bézier.
+    pub fn reflected_quadratic_control(&self) -> Point {
+        self.quadratic_control
+            .map(|con| self.current * 2.0 - con)
+            .unwrap_or(self.current)
+    }
+
+    /// Move the cursor through a command, returning its absolute variant.
+    pub fn advance(&mut self, cmd: &PathCommandKind) -> PathCommandKind {
+        let cur = self.current;
+
+        let abs = match *cmd {
+            PathCommandKind::MoveToAbs(to) => PathCommandKind::MoveToAbs(to),
+            PathCommandKind::MoveToRel(d) => PathCommandKind::MoveToAbs(cur + d),
+            PathCommandKind::LineAbs(to) => PathCommandKind::LineAbs(to),
+            PathCommandKind::LineRel(d) => PathCommandKind::LineAbs(cur + d),
+            PathCommandKind::HorizontalAbs(x) => PathCommandKind::HorizontalAbs(x),
+            PathCommandKind::HorizontalRel(dx) => PathCommandKind::HorizontalAbs(cur.0 + dx),
+            PathCommandKind::VerticalAbs(y) => PathCommandKind::VerticalAbs(y),
+            PathCommandKind::VerticalRel(dy) => PathCommandKind::VerticalAbs(cur.1 + dy),
+            PathCommandKind::CubicBezierAbs { con_start, con_end, to } => PathCommandKind::CubicBezierAbs { con_start, con_end, to },
+            PathCommandKind::CubicBezierRel { d_con_start, d_con_end, d_to } => PathCommandKind::CubicBezierAbs {
+                con_start: cur + d_con_start,
+                con_end: cur + d_con_end,
+                to: cur + d_to,
+            },
+            PathCommandKind::SmoothCubicBezierAbs { con_end, to } => PathCommandKind::SmoothCubicBezierAbs { con_end, to },
+            PathCommandKind::SmoothCubicBezierRel { d_con_end, d_to } => PathCommandKind::SmoothCubicBezierAbs {
+                con_end: cur + d_con_end,
+                to: cur + d_to,
+            },
+            PathCommandKind::QuadraticBezierAbs { con, to } => PathCommandKind::QuadraticBezierAbs { con, to },
+            PathCommandKind::QuadraticBezierRel { d_con, d_to } => PathCommandKind::QuadraticBezierAbs {
+                con: cur + d_con,
+                to: cur + d_to,
+            },
+            PathCommandKind::SmoothQuadraticBezierAbs(to) => PathCommandKind::SmoothQuadraticBezierAbs(to),
+            PathCommandKind::SmoothQuadraticBezierRel(d_to) => PathCommandKind::SmoothQuadraticBezierAbs(cur + d_to),
+            PathCommandKind::EllipticalArcAbs { radius_x, radius_y, angle, laf, sf, shift } => PathCommandKind::EllipticalArcAbs {
+                radius_x, radius_y, angle, laf, sf, shift,
+            },
+            PathCommandKind::EllipticalArcRel { radius_x, radius_y, angle, laf, sf, d_shift } => PathCommandKind::EllipticalArcAbs {
+                radius_x, radius_y, angle, laf, sf,
+                shift: cur + d_shift,
+            },
+            PathCommandKind::Close => PathCommandKind::Close,
+        };
+
+        let (cubic_control, quadratic_control) = match abs {
+            PathCommandKind::CubicBezierAbs { con_end, .. } |
+                PathCommandKind::SmoothCubicBezierAbs { con_end, .. } => (Some(con_end), None),
+            PathCommandKind::QuadraticBezierAbs { con, .. } => (None, Some(con)),
+            PathCommandKind::SmoothQuadraticBezierAbs(_) => (None, Some(self.reflected_quadratic_control())),
+            _ => (None, None),
+        };
+
+        self.cubic_control = cubic_control;
+        self.quadratic_control = quadratic_control;
+
+        match abs {
+            PathCommandKind::MoveToAbs(to) => {
+                self.current = to;
+                self.subpath_start = to;
+            }
+            PathCommandKind::LineAbs(to) |
+                PathCommandKind::CubicBezierAbs { to, .. } |
+                PathCommandKind::SmoothCubicBezierAbs { to, .. } |
+                PathCommandKind::QuadraticBezierAbs { to, .. } |
+                PathCommandKind::SmoothQuadraticBezierAbs(to) |
+                PathCommandKind::EllipticalArcAbs { shift: to, .. } =>
+            {
+                self.current = to;
+            }
+            PathCommandKind::HorizontalAbs(x) => self.current.0 = x,
+            PathCommandKind::VerticalAbs(y) => self.current.1 = y,
+            PathCommandKind::Close => self.current = self.subpath_start,
+            _ => {}
+        }
+
+        abs
+    }
+}
+
+impl FromIterator<PathCommandKind> for PathData {
+    fn from_iter<T: IntoIterator<Item = PathCommandKind>>(iter: T) -> Self {
+        Self {
+            cmds: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<PathCommandKind> for PathData {
+    fn extend<T: IntoIterator<Item = PathCommandKind>>(&mut self, iter: T) {
+        self.cmds.extend(iter);
+    }
 }
 
 fn to_cmd_str(cmd: &PathCommandKind) -> String {
@@ -287,75 +559,58 @@ fn to_cmd_str(cmd: &PathCommandKind) -> String {
     }.to_string()
 }
 
-impl ToString for PathData {
-    fn to_string(&self) -> String {
+/// Argument of a path command, as written in path data
+enum Argument {
+    Number(f32),
+    Flag(u8),
+    Point(Point),
+}
+
+fn to_arguments(cmd: &PathCommandKind) -> Vec<Argument> {
+    use Argument::{Flag, Number, Point as P};
+
+    match cmd {
+        PathCommandKind::MoveToAbs(point) |
+            PathCommandKind::MoveToRel(point) |
+            PathCommandKind::LineAbs(point) |
+            PathCommandKind::LineRel(point) |
+            PathCommandKind::SmoothQuadraticBezierAbs(point) |
+            PathCommandKind::SmoothQuadraticBezierRel(point) => vec![P(*point)],
+        PathCommandKind::HorizontalAbs(coord) |
+            PathCommandKind::HorizontalRel(coord) |
+            PathCommandKind::VerticalAbs(coord) |
+            PathCommandKind::VerticalRel(coord) => vec![Number(*coord)],
+        PathCommandKind::CubicBezierAbs { con_start, con_end, to } => vec![P(*con_start), P(*con_end), P(*to)],
+        PathCommandKind::CubicBezierRel { d_con_start, d_con_end, d_to } => vec![P(*d_con_start), P(*d_con_end), P(*d_to)],
+        PathCommandKind::SmoothCubicBezierAbs { con_end, to } => vec![P(*con_end), P(*to)],
+        PathCommandKind::SmoothCubicBezierRel { d_con_end, d_to } => vec![P(*d_con_end), P(*d_to)],
+        PathCommandKind::QuadraticBezierAbs { con, to } => vec![P(*con), P(*to)],
+        PathCommandKind::QuadraticBezierRel { d_con, d_to } => vec![P(*d_con), P(*d_to)],
+        PathCommandKind::EllipticalArcAbs { radius_x, radius_y, angle, laf, sf, shift } => {
+            vec![Number(*radius_x), Number(*radius_y), Number(*angle), Flag(*laf as u8), Flag(*sf as u8), P(*shift)]
+        }
+        PathCommandKind::EllipticalArcRel { radius_x, radius_y, angle, laf, sf, d_shift } => {
+            vec![Number(*radius_x), Number(*radius_y), Number(*angle), Flag(*laf as u8), Flag(*sf as u8), P(*d_shift)]
+        }
+        PathCommandKind::Close => vec![],
+    }
+}
+
+impl PathData {
+    /// Write path data with one space between tokens, e.g. `M 0,0 L 1,1`.
+    fn to_readable_string(&self, options: &SerializeOptions) -> String {
         let mut clauses: Vec<String> = Vec::new();
         let mut tokens: Vec<String> = Vec::with_capacity(7);
 
         for entry in self.cmds.iter() {
-
             tokens.push(to_cmd_str(entry));
 
-            match entry {
-                PathCommandKind::MoveToAbs(point) | 
-                    PathCommandKind::MoveToRel(point) | 
-                    PathCommandKind::LineAbs(point) | 
-                    PathCommandKind::LineRel(point) |
-                    PathCommandKind::SmoothQuadraticBezierAbs(point) |
-                    PathCommandKind::SmoothQuadraticBezierRel(point) =>
-                {
-                    tokens.push(point.to_string());
-                }
-                PathCommandKind::HorizontalAbs(coord) |
-                    PathCommandKind::HorizontalRel(coord) |
-                    PathCommandKind::VerticalAbs(coord) |
-                    PathCommandKind::VerticalRel(coord) =>
-                {
-                    tokens.push(coord.to_string());
-                }
-                PathCommandKind::CubicBezierAbs { con_start, con_end, to } => {
-                    tokens.push(con_start.to_string());
-                    tokens.push(con_end.to_string());
-                    tokens.push(to.to_string());
-                }
-                PathCommandKind::CubicBezierRel { d_con_start, d_con_end, d_to } => {
-                    tokens.push(d_con_start.to_string());
-                    tokens.push(d_con_end.to_string());
-                    tokens.push(d_to.to_string());
-                }
-                PathCommandKind::SmoothCubicBezierAbs { con_end, to } => {
-                    tokens.push(con_end.to_string());
-                    tokens.push(to.to_string());
-                }
-                PathCommandKind::SmoothCubicBezierRel { d_con_end, d_to } => {
-                    tokens.push(d_con_end.to_string());
-                    tokens.push(d_to.to_string());
-                }
-                PathCommandKind::QuadraticBezierAbs { con, to } => {
-                    tokens.push(con.to_string());
-                    tokens.push(to.to_string());
-                }
-                PathCommandKind::QuadraticBezierRel { d_con, d_to } => {
-                    tokens.push(d_con.to_string());
-                    tokens.push(d_to.to_string());
-                }
-                PathCommandKind::EllipticalArcAbs { radius_x, radius_y, angle, laf, sf, shift } => {
-                    tokens.push(radius_x.to_string());
-                    tokens.push(radius_y.to_string());
-                    tokens.push(angle.to_string());
-                    tokens.push((*laf as u8).to_string());
-                    tokens.push((*sf as u8).to_string());
-                    tokens.push(shift.to_string());
-                }
-                PathCommandKind::EllipticalArcRel { radius_x, radius_y, angle, laf, sf, d_shift } => {
-                    tokens.push(radius_x.to_string());
-                    tokens.push(radius_y.to_string());
-                    tokens.push(angle.to_string());
-                    tokens.push((*laf as u8).to_string());
-                    tokens.push((*sf as u8).to_string());
-                    tokens.push(d_shift.to_string());
-                }
-                PathCommandKind::Close => {}
+            for arg in to_arguments(entry) {
+                tokens.push(match arg {
+                    Argument::Number(value) => value.format_value(options),
+                    Argument::Flag(flag) => flag.to_string(),
+                    Argument::Point(point) => point.format_value(options),
+                });
             }
 
             clauses.push(tokens.join(" "));
@@ -364,4 +619,105 @@ impl ToString for PathData {
 
         clauses.join(" ")
     }
+
+    /// Write path data without redundant separators and command letters, e.g. `M0 0 1-1`.
+    fn to_compact_string(&self, options: &SerializeOptions) -> String {
+        let mut out = String::new();
+        let mut last_cmd: Option<String> = None;
+
+        for entry in self.cmds.iter() {
+            let cmd = to_cmd_str(entry);
+            let args = to_arguments(entry);
+
+            // Following coordinates repeat the command, or draw lines after a move
+            let implied = match last_cmd.as_deref() {
+                Some("M") => Some("L"),
+                Some("m") => Some("l"),
+                last => last,
+            };
+
+            if args.is_empty() || implied != Some(cmd.as_str()) {
+                out.push_str(&cmd);
+            }
+
+            for arg in args {
+                match arg {
+                    Argument::Number(value) => join_compact(&mut out, &value.format_value(options)),
+                    Argument::Flag(flag) => join_compact(&mut out, &flag.to_string()),
+                    Argument::Point(point) => {
+                        join_compact(&mut out, &point.0.format_value(options));
+                        join_compact(&mut out, &point.1.format_value(options));
+                    }
+                }
+            }
+
+            last_cmd = Some(cmd);
+        }
+
+        out
+    }
+}
+
+impl FormatValue for PathData {
+    fn format_value(&self, options: &SerializeOptions) -> String {
+        if options.compact_paths {
+            self.to_compact_string(options)
+        } else {
+            self.to_readable_string(options)
+        }
+    }
+}
+
+impl Display for PathData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.format_value(&SerializeOptions::default()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn edit_path_data() {
+        let mut path = Path::from_data("M 0,0 L 10,0".parse().unwrap());
+        path.data_mut().draw_line(Point(10.0, 10.0)).close();
+
+        assert_eq!(path.to_string(), r#"<path d="M 0,0 L 10,0 L 10,10 Z"/>"#);
+    }
+
+    #[test]
+    fn build_path_data() {
+        let start = Point::new(1.0, 2.0);
+        let end = start + Point::from((2.0, 2.0)) * 2.0;
+
+        let data: PathData = [
+            PathCommandKind::MoveToAbs(start),
+            PathCommandKind::LineAbs(start.lerp(&end, 0.5)),
+        ].into_iter().collect();
+
+        assert_eq!(data.to_string(), "M 1,2 L 3,4");
+        assert_eq!(start.distance(&Point::new(4.0, 6.0)), 5.0);
+    }
+
+    #[test]
+    fn convert_absolute_relative() {
+        let data: PathData = "m 1 1 h 2 v 2 c 1 0 1 1 0 1 s -1 1 -1 0 t 1 1 a 1 1 0 0 1 2 2 z l 1 1".parse().unwrap();
+
+        let abs = data.to_absolute();
+        assert_eq!(abs.to_string(), "M 1,1 H 3 V 3 C 4,3 4,4 3,4 S 2,5 2,4 T 3,5 A 1 1 0 0 1 5,7 Z L 2,2");
+        assert_eq!(abs.to_relative(), data);
+    }
+
+    #[test]
+    fn reflect_control_points() {
+        let mut cursor = PathCursor::new();
+
+        for cmd in "M 0 0 Q 1 1 2 0 T 4 0".parse::<PathData>().unwrap().commands() {
+            cursor.advance(cmd);
+        }
+
+        assert_eq!(cursor.reflected_quadratic_control(), Point::new(5.0, 1.0));
+        assert_eq!(cursor.reflected_cubic_control(), Point::new(4.0, 0.0));
+    }
 }
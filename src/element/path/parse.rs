@@ -0,0 +1,160 @@
+use std::str::FromStr;
+
+use crate::{error::Error, parser::scanner::Scanner, Point};
+
+use super::{LargeArcFlag, PathCommandKind, PathData, SweepFlag};
+
+fn point(scanner: &mut Scanner) -> Result<Point, Error> {
+    let x = scanner.number()?;
+    let y = scanner.next_number()?;
+
+    Ok(Point(x, y))
+}
+
+fn next_point(scanner: &mut Scanner) -> Result<Point, Error> {
+    scanner.skip_comma_wsp();
+    point(scanner)
+}
+
+/// Read one set of arguments for the command.
+fn arguments(scanner: &mut Scanner, cmd: u8) -> Result<PathCommandKind, Error> {
+    Ok(match cmd {
+        b'M' => PathCommandKind::MoveToAbs(point(scanner)?),
+        b'm' => PathCommandKind::MoveToRel(point(scanner)?),
+        b'L' => PathCommandKind::LineAbs(point(scanner)?),
+        b'l' => PathCommandKind::LineRel(point(scanner)?),
+        b'H' => PathCommandKind::HorizontalAbs(scanner.number()?),
+        b'h' => PathCommandKind::HorizontalRel(scanner.number()?),
+        b'V' => PathCommandKind::VerticalAbs(scanner.number()?),
+        b'v' => PathCommandKind::VerticalRel(scanner.number()?),
+        b'C' => PathCommandKind::CubicBezierAbs {
+            con_start: point(scanner)?,
+            con_end: next_point(scanner)?,
+            to: next_point(scanner)?,
+        },
+        b'c' => PathCommandKind::CubicBezierRel {
+            d_con_start: point(scanner)?,
+            d_con_end: next_point(scanner)?,
+            d_to: next_point(scanner)?,
+        },
+        b'S' => PathCommandKind::SmoothCubicBezierAbs {
+            con_end: point(scanner)?,
+            to: next_point(scanner)?,
+        },
+        b's' => PathCommandKind::SmoothCubicBezierRel {
+            d_con_end: point(scanner)?,
+            d_to: next_point(scanner)?,
+        },
+        b'Q' => PathCommandKind::QuadraticBezierAbs {
+            con: point(scanner)?,
+            to: next_point(scanner)?,
+        },
+        b'q' => PathCommandKind::QuadraticBezierRel {
+            d_con: point(scanner)?,
+            d_to: next_point(scanner)?,
+        },
+        b'T' => PathCommandKind::SmoothQuadraticBezierAbs(point(scanner)?),
+        b't' => PathCommandKind::SmoothQuadraticBezierRel(point(scanner)?),
+        b'A' | b'a' => {
+            let radius_x = scanner.number()?;
+            let radius_y = scanner.next_number()?;
+            let angle = scanner.next_number()?;
+            let laf = if scanner.next_flag()? { LargeArcFlag::Large } else { LargeArcFlag::Small };
+            let sf = if scanner.next_flag()? { SweepFlag::Clockwise } else { SweepFlag::CounterClockwise };
+            let shift = next_point(scanner)?;
+
+            if cmd == b'A' {
+                PathCommandKind::EllipticalArcAbs { radius_x, radius_y, angle, laf, sf, shift }
+            } else {
+                PathCommandKind::EllipticalArcRel { radius_x, radius_y, angle, laf, sf, d_shift: shift }
+            }
+        }
+        _ => return Err(scanner.error()),
+    })
+}
+
+impl FromStr for PathData {
+    type Err = Error;
+
+    /// Parse the `d` attribute grammar.
+    ///
+    /// On malformed input, the error carries the byte offset
+    /// where parsing has failed.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(s, Error::InvalidPathData);
+        let mut data = PathData::new();
+
+        scanner.skip_wsp();
+
+        while let Some(cmd) = scanner.peek() {
+            // Path data must begin with a "move to" command
+            if data.cmds.is_empty() && !matches!(cmd, b'M' | b'm') {
+                return Err(scanner.error());
+            }
+
+            if !b"MmLlHhVvCcSsQqTtAaZz".contains(&cmd) {
+                return Err(scanner.error());
+            }
+
+            scanner.bump();
+            scanner.skip_wsp();
+
+            if matches!(cmd, b'Z' | b'z') {
+                data.cmds.push(PathCommandKind::Close);
+                continue;
+            }
+
+            let mut cmd = cmd;
+
+            loop {
+                data.cmds.push(arguments(&mut scanner, cmd)?);
+
+                // A trailing comma must be followed by another set of arguments
+                if !scanner.skip_comma_wsp() && !scanner.starts_number() {
+                    break;
+                }
+
+                // Implicit commands following "move to" are "line to"
+                cmd = match cmd {
+                    b'M' => b'L',
+                    b'm' => b'l',
+                    _ => cmd,
+                };
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_implicit_commands() {
+        let data: PathData = "M0,0 10,10 l5 5 5 5 Z m1 1 2 2z".parse().unwrap();
+        assert_eq!(data.to_string(), "M 0,0 L 10,10 l 5,5 l 5,5 Z m 1,1 l 2,2 Z");
+    }
+
+    #[test]
+    fn parse_compact_numbers() {
+        let data: PathData = "M1.5.5L-1-2e1h1e-3V.5".parse().unwrap();
+        assert_eq!(data.to_string(), "M 1.5,0.5 L -1,-20 h 0.001 V 0.5");
+    }
+
+    #[test]
+    fn parse_arc_flags() {
+        let data: PathData = "M0 0a25,25 -30 0110 50A1 2 3 1 0 4 5".parse().unwrap();
+        assert_eq!(data.to_string(), "M 0,0 a 25 25 -30 0 1 10,50 A 1 2 3 1 0 4,5");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!("L 0 0".parse::<PathData>(), Err(Error::InvalidPathData(0))));
+        assert!(matches!("M 0 0 L 1".parse::<PathData>(), Err(Error::InvalidPathData(9))));
+        assert!(matches!("M 0 0 X 1 1".parse::<PathData>(), Err(Error::InvalidPathData(6))));
+        assert!(matches!("M 0 0 A 1 1 0 2 0 1 1".parse::<PathData>(), Err(Error::InvalidPathData(14))));
+        assert!(matches!("M 0 0,".parse::<PathData>(), Err(Error::InvalidPathData(6))));
+    }
+}
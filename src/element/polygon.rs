@@ -1,8 +1,8 @@
 use quick_xml::events::BytesStart;
 
-use crate::{attr::{impl_attr_accessors, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr};
+use crate::{attr::{impl_attr_accessors, transform::Matrix, LazyAttrMap}, bbox::{local_matrix, points_bbox, BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
 
-use super::{impl_accessor, impl_element, LazyChildList, TagName, WriteXml};
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
 
 /// SVG polygon element (<polygon>)
 ///
@@ -17,7 +17,6 @@ pub struct Polygon {
 
 impl Default for Polygon {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self {
             points: None,
@@ -34,9 +33,10 @@ impl Polygon {
     where
         T: ToString,
     {
-        let mut p = Self::default();
-        p.points = Some(points.to_string());
-        p
+        Self {
+            points: Some(points.to_string()),
+            ..Default::default()
+        }
     }
 
     impl_accessor!(string* -> points, set_points, "points");
@@ -47,16 +47,34 @@ impl_element!(Polygon, "polygon");
 impl_attr_accessors!(Polygon);
 
 impl WriteXml for Polygon {
-    fn write_xml(&self, writer: &mut quick_xml::Writer<std::io::Cursor<Vec<u8>>>) -> Result<(), crate::error::Error> {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
         let tag = Self::tag_name();
 
         let mut bs = BytesStart::new(tag);
 
         push_attr!(self.points, bs, "points" <- String);
-        push_attr!(self.path_length, bs, "pathLength" <- prim);
+        push_attr!(self.path_length, bs, "pathLength" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Polygon {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "points" => pull_attr!(self.points, value <- String),
+            "pathLength" => pull_attr!(self.path_length, value <- prim),
+            _ => pull_attr!(map: self, key, value),
+        }
 
-        push_attr!(map: self.attr, bs);
+        Ok(())
+    }
+}
 
-        convert_into_xml(writer, bs, self.children(), tag)
+impl BBox for Polygon {
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        points_bbox(self.points()?, &local_matrix(self, matrix)?)
     }
 }
@@ -0,0 +1,96 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, transform::Matrix, LazyAttrMap}, bbox::{local_matrix, points_bbox, BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// SVG polyline element (<polyline>)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/polyline).
+pub struct Polyline {
+    points: Option<String>,
+    path_length: Option<f32>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Polyline {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            points: None,
+            path_length: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Polyline {
+    #[inline]
+    pub fn new<T>(points: &T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            points: Some(points.to_string()),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(string* -> points, set_points, "points");
+    impl_accessor!(primitive -> path_length, set_path_length, f32, "pathLength");
+}
+
+impl_element!(Polyline, "polyline");
+impl_attr_accessors!(Polyline);
+
+impl WriteXml for Polyline {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.points, bs, "points" <- String);
+        push_attr!(self.path_length, bs, "pathLength" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Polyline {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "points" => pull_attr!(self.points, value <- String),
+            "pathLength" => pull_attr!(self.path_length, value <- prim),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Polyline {
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        points_bbox(self.points()?, &local_matrix(self, matrix)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn write_and_parse() {
+        let mut polyline = Polyline::new(&"0,0 1,1");
+        polyline.set_path_length(Some(2.0));
+
+        let expected = r#"<polyline points="0,0 1,1" pathLength="2"/>"#;
+
+        assert_eq!(polyline.to_string(), expected);
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+    }
+}
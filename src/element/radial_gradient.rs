@@ -0,0 +1,137 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{color::Color, impl_attr_accessors, length::Length, paint::Paint, spread_method::SpreadMethod, transform::{Matrix, Transform}, units::Units, AccessAttr, Attr, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, stop::stop_list, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Radial gradient element (`<radialGradient>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/radialGradient).
+pub struct RadialGradient {
+    cx: Option<Length>,
+    cy: Option<Length>,
+    r: Option<Length>,
+    fx: Option<Length>,
+    fy: Option<Length>,
+    fr: Option<Length>,
+
+    gradient_units: Option<Units>,
+    gradient_transform: Option<Transform>,
+    spread_method: Option<SpreadMethod>,
+    href: Option<String>,
+    xlink: bool,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for RadialGradient {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            cx: None,
+            cy: None,
+            r: None,
+            fx: None,
+            fy: None,
+            fr: None,
+            gradient_units: None,
+            gradient_transform: None,
+            spread_method: None,
+            href: None,
+            xlink: false,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl RadialGradient {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a gradient with `<stop>` children from `(offset, color)` pairs,
+    /// where offsets range from `0.0` to `1.0`.
+    pub fn with_stops<I, C>(stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: Into<Color>,
+    {
+        Self {
+            children: Some(stop_list(stops)),
+            ..Default::default()
+        }
+    }
+
+    /// Paint referencing the gradient by its `id`, or `None` without `id`.
+    pub fn paint(&self) -> Option<Paint> {
+        Some(Paint::url(self.attr(&Attr::from("id"))?))
+    }
+
+    impl_accessor!(length -> cx, set_cx, "cx");
+    impl_accessor!(length -> cy, set_cy, "cy");
+    impl_accessor!(length -> r, set_r, "r");
+    impl_accessor!(length -> fx, set_fx, "fx");
+    impl_accessor!(length -> fy, set_fy, "fy");
+    impl_accessor!(length -> fr, set_fr, "fr");
+    impl_accessor!(primitive -> gradient_units, set_gradient_units, Units, "gradientUnits");
+    impl_accessor!(ref:clone_setter -> gradient_transform, set_gradient_transform, "gradientTransform", Transform);
+    impl_accessor!(primitive -> spread_method, set_spread_method, SpreadMethod, "spreadMethod");
+    impl_accessor!(href -> href, set_href);
+}
+
+impl_element!(RadialGradient, "radialGradient");
+impl_attr_accessors!(RadialGradient);
+
+impl WriteXml for RadialGradient {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.cx, bs, "cx" <- Format(options));
+        push_attr!(self.cy, bs, "cy" <- Format(options));
+        push_attr!(self.r, bs, "r" <- Format(options));
+        push_attr!(self.fx, bs, "fx" <- Format(options));
+        push_attr!(self.fy, bs, "fy" <- Format(options));
+        push_attr!(self.fr, bs, "fr" <- Format(options));
+        push_attr!(self.gradient_units, bs, "gradientUnits" <- ToString);
+        push_attr!(self.gradient_transform, bs, "gradientTransform" <- ToString);
+        push_attr!(self.spread_method, bs, "spreadMethod" <- ToString);
+        push_attr!(href: self, bs);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for RadialGradient {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "cx" => pull_attr!(self.cx, value <- FromStr | map: self, key),
+            "cy" => pull_attr!(self.cy, value <- FromStr | map: self, key),
+            "r" => pull_attr!(self.r, value <- FromStr | map: self, key),
+            "fx" => pull_attr!(self.fx, value <- FromStr | map: self, key),
+            "fy" => pull_attr!(self.fy, value <- FromStr | map: self, key),
+            "fr" => pull_attr!(self.fr, value <- FromStr | map: self, key),
+            "gradientUnits" => pull_attr!(self.gradient_units, value <- FromStr),
+            "gradientTransform" => pull_attr!(self.gradient_transform, value <- FromStr),
+            "spreadMethod" => pull_attr!(self.spread_method, value <- FromStr),
+            "href" | "xlink:href" => pull_attr!(href: self, key, value),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for RadialGradient {
+    /// Paint servers are not rendered.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
@@ -1,21 +1,21 @@
 use quick_xml::events::BytesStart;
 
-use crate::{attr::{impl_attr_accessors, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr};
+use crate::{attr::{impl_attr_accessors, length::Length, transform::Matrix, LazyAttrMap}, bbox::{local_matrix, path_bbox, BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions, Point};
 
-use super::{impl_accessor, impl_element, ChildList, TagName, WriteXml};
+use super::{impl_accessor, impl_element, path::{LargeArcFlag, PathData, SweepFlag}, ChildList, ReadXml, TagName, WriteXml};
 
 /// Rectangle element (`<rect>`)
 ///
 /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/rect).
 pub struct Rect {
-    x: Option<String>,
-    y: Option<String>,
+    x: Option<Length>,
+    y: Option<Length>,
 
-    width: Option<String>,
-    height: Option<String>,
+    width: Option<Length>,
+    height: Option<Length>,
 
-    rx: Option<String>,
-    ry: Option<String>,
+    rx: Option<Length>,
+    ry: Option<Length>,
 
     path_length: Option<f32>,
 
@@ -26,7 +26,6 @@ pub struct Rect {
 
 impl Default for Rect {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self {
             x: None,
@@ -44,12 +43,12 @@ impl Default for Rect {
 
 impl Rect {
     /// Create an SVG rectangle with `x`, `y`, `width`, and `height`.
-    pub fn new<TX, TY, TW, TH>(x: &TX, y: &TY, width: &TW, height: &TH) -> Self
+    pub fn new<TX, TY, TW, TH>(x: TX, y: TY, width: TW, height: TH) -> Self
     where
-        TX: ToString,
-        TY: ToString,
-        TW: ToString,
-        TH: ToString,
+        TX: Into<Length>,
+        TY: Into<Length>,
+        TW: Into<Length>,
+        TH: Into<Length>,
     {
         let mut rect = Self::default();
 
@@ -61,32 +60,97 @@ impl Rect {
         rect
     }
 
-    impl_accessor!(string* -> x, set_x, "x");
-    impl_accessor!(string* -> y, set_y, "y");
-    impl_accessor!(string* -> width, set_width, "width");
-    impl_accessor!(string* -> height, set_height, "height");
-    impl_accessor!(string* -> rx, set_rx, "rx");
-    impl_accessor!(string* -> ry, set_ry, "ry");
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(length -> rx, set_rx, "rx");
+    impl_accessor!(length -> ry, set_ry, "ry");
     impl_accessor!(primitive -> path_length, set_path_length, f32, "pathLength");
 }
 
 impl WriteXml for Rect {
-    fn write_xml(&self, writer: &mut quick_xml::Writer<std::io::Cursor<Vec<u8>>>) -> Result<(), crate::error::Error> {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
         let tag = Self::tag_name();
 
         let mut bs = BytesStart::new(tag);
 
-        push_attr!(self.x, bs, "x" <- String);
-        push_attr!(self.y, bs, "y" <- String);
-        push_attr!(self.width, bs, "width" <- String);
-        push_attr!(self.height, bs, "height" <- String);
-        push_attr!(self.rx, bs, "rx" <- String);
-        push_attr!(self.ry, bs, "ry" <- String);
-        push_attr!(self.path_length, bs, "pathLength" <- prim);
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.rx, bs, "rx" <- Format(options));
+        push_attr!(self.ry, bs, "ry" <- Format(options));
+        push_attr!(self.path_length, bs, "pathLength" <- Format(options));
 
-        push_attr!(map: self.attr, bs);
+        push_attr!(map: self.attr, bs, options);
 
-        convert_into_xml(writer, bs, self.children(), tag)
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Rect {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "rx" => pull_attr!(self.rx, value <- FromStr | map: self, key),
+            "ry" => pull_attr!(self.ry, value <- FromStr | map: self, key),
+            "pathLength" => pull_attr!(self.path_length, value <- prim),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Rect {
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        let matrix = local_matrix(self, matrix)?;
+
+        let x = self.x.unwrap_or_default().to_absolute_user_units()?;
+        let y = self.y.unwrap_or_default().to_absolute_user_units()?;
+        let width = self.width?.to_absolute_user_units()?;
+        let height = self.height?.to_absolute_user_units()?;
+
+        if width <= 0.0 || height <= 0.0 {
+            return None;
+        }
+
+        // Either corner radius defaults to the other one
+        let (rx, ry) = match (self.rx.and_then(|rx| rx.to_absolute_user_units()), self.ry.and_then(|ry| ry.to_absolute_user_units())) {
+            (Some(rx), Some(ry)) => (rx, ry),
+            (Some(r), None) | (None, Some(r)) => (r, r),
+            (None, None) => (0.0, 0.0),
+        };
+
+        let rx = rx.clamp(0.0, width / 2.0);
+        let ry = ry.clamp(0.0, height / 2.0);
+
+        let mut data = PathData::new();
+
+        if rx > 0.0 && ry > 0.0 {
+            data.move_to(Point(x + rx, y))
+                .draw_horizonal_line(x + width - rx)
+                .draw_elliptical_arc(rx, ry, 0.0, LargeArcFlag::Small, SweepFlag::Clockwise, Point(x + width, y + ry))
+                .draw_vertical_line(y + height - ry)
+                .draw_elliptical_arc(rx, ry, 0.0, LargeArcFlag::Small, SweepFlag::Clockwise, Point(x + width - rx, y + height))
+                .draw_horizonal_line(x + rx)
+                .draw_elliptical_arc(rx, ry, 0.0, LargeArcFlag::Small, SweepFlag::Clockwise, Point(x, y + height - ry))
+                .draw_vertical_line(y + ry)
+                .draw_elliptical_arc(rx, ry, 0.0, LargeArcFlag::Small, SweepFlag::Clockwise, Point(x + rx, y))
+                .close();
+        } else {
+            data.move_to(Point(x, y))
+                .draw_horizonal_line(x + width)
+                .draw_vertical_line(y + height)
+                .draw_horizonal_line(x)
+                .close();
+        }
+
+        path_bbox(&data, &matrix)
     }
 }
 
@@ -0,0 +1,124 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{animation::{AnimationFill, Duration, RepeatCount, Restart, Timing}, impl_attr_accessors, LazyAttrMap}, bbox::impl_no_bbox, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Set element (`<set>`)
+///
+/// The target is the parent element, or the one referenced by `href`.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/set).
+pub struct Set {
+    href: Option<String>,
+    xlink: bool,
+    attribute_name: Option<String>,
+    to: Option<String>,
+    begin: Option<Vec<Timing>>,
+    dur: Option<Duration>,
+    end: Option<Vec<Timing>>,
+    min: Option<Duration>,
+    max: Option<Duration>,
+    restart: Option<Restart>,
+    repeat_count: Option<RepeatCount>,
+    repeat_dur: Option<Duration>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Set {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            href: None,
+            xlink: false,
+            attribute_name: None,
+            to: None,
+            begin: None,
+            dur: None,
+            end: None,
+            min: None,
+            max: None,
+            restart: None,
+            repeat_count: None,
+            repeat_dur: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Set {
+    /// Set `attribute_name` to `to` when the animation begins.
+    pub fn new(attribute_name: &str, to: &str) -> Self {
+        Self {
+            attribute_name: Some(attribute_name.to_string()),
+            to: Some(to.to_string()),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(href -> href, set_href);
+    impl_accessor!(string -> attribute_name, set_attribute_name, "attributeName");
+    impl_accessor!(string -> to, set_to, "to");
+    impl_accessor!(list:primitive -> begin, begin_mut, "begin", Timing);
+    impl_accessor!(primitive -> dur, set_dur, Duration, "dur");
+    impl_accessor!(list:primitive -> end, end_mut, "end", Timing);
+    impl_accessor!(primitive -> min, set_min, Duration, "min");
+    impl_accessor!(primitive -> max, set_max, Duration, "max");
+    impl_accessor!(primitive -> restart, set_restart, Restart, "restart");
+    impl_accessor!(primitive -> repeat_count, set_repeat_count, RepeatCount, "repeatCount");
+    impl_accessor!(primitive -> repeat_dur, set_repeat_dur, Duration, "repeatDur");
+    impl_accessor!(map -> fill, set_fill, AnimationFill, "fill");
+}
+
+impl_element!(Set, "set");
+impl_attr_accessors!(Set);
+
+impl WriteXml for Set {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(href: self, bs);
+        push_attr!(self.attribute_name, bs, "attributeName" <- String);
+        push_attr!(self.to, bs, "to" <- String);
+        push_attr!(self.begin, bs, "begin" <- primitives | ";");
+        push_attr!(self.dur, bs, "dur" <- ToString);
+        push_attr!(self.end, bs, "end" <- primitives | ";");
+        push_attr!(self.min, bs, "min" <- ToString);
+        push_attr!(self.max, bs, "max" <- ToString);
+        push_attr!(self.restart, bs, "restart" <- ToString);
+        push_attr!(self.repeat_count, bs, "repeatCount" <- ToString);
+        push_attr!(self.repeat_dur, bs, "repeatDur" <- ToString);
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Set {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "href" | "xlink:href" => pull_attr!(href: self, key, value),
+            "attributeName" => pull_attr!(self.attribute_name, value <- String),
+            "to" => pull_attr!(self.to, value <- String),
+            "begin" => pull_attr!(self.begin, value <- primitives | ';'),
+            "dur" => pull_attr!(self.dur, value <- FromStr),
+            "end" => pull_attr!(self.end, value <- primitives | ';'),
+            "min" => pull_attr!(self.min, value <- FromStr),
+            "max" => pull_attr!(self.max, value <- FromStr),
+            "restart" => pull_attr!(self.restart, value <- FromStr),
+            "repeatCount" => pull_attr!(self.repeat_count, value <- FromStr),
+            "repeatDur" => pull_attr!(self.repeat_dur, value <- FromStr),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl_no_bbox!(animation: Set);
@@ -0,0 +1,127 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{color::Color, impl_attr_accessors, length::Length, transform::Matrix, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, ChildKind, ChildList, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Gradient stop element (`<stop>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/stop).
+pub struct Stop {
+    offset: Option<Length>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Stop {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            offset: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Stop {
+    /// Create a stop at `offset`, from `0.0` to `1.0` along the gradient vector.
+    pub fn new<C>(offset: f32, color: C) -> Self
+    where
+        C: Into<Color>,
+    {
+        let mut stop = Self {
+            offset: Some(Length::user(offset)),
+            ..Default::default()
+        };
+
+        stop.set_stop_color(Some(color.into()));
+
+        stop
+    }
+
+    impl_accessor!(length -> offset, set_offset, "offset");
+    impl_accessor!(map -> stop_color, set_stop_color, Color, "stop-color");
+    impl_accessor!(map -> stop_opacity, set_stop_opacity, f32, "stop-opacity");
+}
+
+/// Build `<stop>` children from `(offset, color)` pairs.
+pub(crate) fn stop_list<I, C>(stops: I) -> ChildList
+where
+    I: IntoIterator<Item = (f32, C)>,
+    C: Into<Color>,
+{
+    stops.into_iter()
+        .map(|(offset, color)| ChildKind::Element(Stop::new(offset, color).into()))
+        .collect()
+}
+
+impl_element!(Stop, "stop");
+impl_attr_accessors!(Stop);
+
+impl WriteXml for Stop {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.offset, bs, "offset" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Stop {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "offset" => pull_attr!(self.offset, value <- FromStr | map: self, key),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Stop {
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{attr::{color::Rgba, AccessAttr, Attr}, element::ElementKind};
+
+    use super::*;
+
+    #[test]
+    fn share_presentation_attributes() {
+        let el: ElementKind = r#"<stop offset="0.5" stop-color="red" stop-opacity="0.5"/>"#.parse().unwrap();
+
+        assert_eq!(el.attr(&Attr::from("stop-color")), Some("red"));
+
+        #[cfg(feature = "attr-presentation")]
+        let el = {
+            let mut el = el;
+            assert_eq!(el.stop_color().unwrap().unwrap(), Color::rgb(255, 0, 0));
+
+            el.set_stop_color(&Color::rgb(0, 0, 255));
+            assert_eq!(el.to_string(), r##"<stop offset="0.5" stop-color="#00f" stop-opacity="0.5"/>"##);
+            el
+        };
+
+        let ElementKind::Stop(mut stop) = el else {
+            unreachable!()
+        };
+
+        stop.set_stop_color(Some(Rgba::new(0, 0, 0, 0.5).into())).set_stop_opacity(None);
+        assert_eq!(stop.stop_color(), Some(Color::from(Rgba::new(0, 0, 0, 0.5))));
+        assert_eq!(stop.stop_opacity(), None);
+        assert_eq!(stop.attr(&Attr::from("stop-color")), Some("rgba(0,0,0,0.5)"));
+        assert_eq!(stop.to_string(), r#"<stop offset="0.5" stop-color="rgba(0,0,0,0.5)"/>"#);
+    }
+}
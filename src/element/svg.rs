@@ -1,22 +1,22 @@
 use quick_xml::events::BytesStart;
 
-use crate::{attr::{impl_attr_accessors, LazyAttrMap}, constants::SVG_NAMESPACE, element::convert_into_xml, push_attr};
+use crate::{attr::{impl_attr_accessors, length::Length, marker::MarkerPosition, transform::Matrix, AccessAttr, AsValue, Attr, view_box::{PreserveAspectRatio, ViewBox}, LazyAttrMap}, bbox::{local_matrix, resolved_children_bbox, BBox, BoundingBox, References}, constants::SVG_NAMESPACE, element::convert_into_xml, push_attr, pull_attr, serialize::SerializeOptions};
 
-use super::{impl_accessor, impl_element, ChildList, TagName, WriteXml};
+use super::{defs::{contains_id, ensure_id}, impl_accessor, impl_element, ChildKind, ChildList, Defs, Desc, ElementKind, Marker, ReadXml, TagName, Title, Use, WriteXml};
 
 /// SVG container element (`<svg>`)
 ///
 /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/svg).
 pub struct Svg {
-    view_box: Option<String>,
+    view_box: Option<ViewBox>,
 
-    width: Option<String>,
-    height: Option<String>,
+    width: Option<Length>,
+    height: Option<Length>,
 
-    x: Option<String>,
-    y: Option<String>,
+    x: Option<Length>,
+    y: Option<Length>,
 
-    preserve_aspect_ratio: Option<String>,
+    preserve_aspect_ratio: Option<PreserveAspectRatio>,
 
     attr: LazyAttrMap,
     children: Option<ChildList>,
@@ -24,7 +24,6 @@ pub struct Svg {
 
 impl Default for Svg {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self {
             view_box: None,
@@ -46,56 +45,390 @@ impl Svg {
     }
 
     /// Initialize SVG element with `viewBox` attribute.
-    pub fn with_view_box<T>(view_box: &T) -> Self
+    pub fn with_view_box<T>(view_box: T) -> Self
     where
-        T: ToString,
+        T: Into<ViewBox>,
     {
         let mut svg = Self::new();
-        
-        svg.set_view_box(Some(view_box));
+
+        svg.set_view_box(Some(view_box.into()));
 
         svg
     }
 
-    impl_accessor!(string* -> view_box, set_view_box, "viewBox");
-    impl_accessor!(string* -> x, set_x, "x");
-    impl_accessor!(string* -> y, set_y, "y");
-    impl_accessor!(string* -> width, set_width, "width");
-    impl_accessor!(string* -> height, set_height, "height");
-    impl_accessor!(string* -> preserve_aspect_ratio, set_preserve_aspect_ratio, "preserveAspectRatio");
+    /// Set `viewBox` to the bounding box of the content.
+    ///
+    /// Returns the bounding box, or `None` with `viewBox` untouched
+    /// if the content has no resolvable geometry.
+    pub fn fit_view_box(&mut self) -> Option<BoundingBox> {
+        let refs = References::collect(self.children.as_ref());
+        let bbox = resolved_children_bbox(self.children.as_ref(), &Matrix::identity(), &refs)?;
+
+        self.set_view_box(Some(bbox.into()));
+
+        Some(bbox)
+    }
+
+    /// Register an element in the first `<defs>` of the document and
+    /// return a `<use>` referencing it.
+    ///
+    /// See [`Svg::define_id`].
+    #[inline]
+    pub fn define<T>(&mut self, el: T) -> Use
+    where
+        T: Into<ElementKind>,
+    {
+        Use::referencing(&self.define_id(el))
+    }
+
+    /// Register an element in the first `<defs>` of the document and
+    /// return its `id`, e.g. for referencing a gradient with [`Paint::url`](crate::attr::paint::Paint::url).
+    ///
+    /// `<defs>` is prepended if missing. The element keeps its `id` if it
+    /// has one, otherwise it is given an `id` made of its tag name and
+    /// a number, unique in the document.
+    pub fn define_id<T>(&mut self, el: T) -> String
+    where
+        T: Into<ElementKind>,
+    {
+        let mut el = el.into();
+
+        // Unique in the whole document, not only in `<defs>`
+        let id = ensure_id(&mut el, |id| contains_id(self.children.as_ref(), id));
+
+        self.defs_mut().define(el);
+
+        id
+    }
+
+    /// Access the first `<defs>`, prepending one if missing.
+    fn defs_mut(&mut self) -> &mut Defs {
+        let children = self.children.get_or_insert_with(Vec::new);
+
+        let index = match children.iter().position(|child| matches!(child, ChildKind::Element(ElementKind::Defs(_)))) {
+            Some(index) => index,
+            None => {
+                children.insert(0, ChildKind::Element(Defs::new().into()));
+                0
+            }
+        };
+
+        match &mut children[index] {
+            ChildKind::Element(ElementKind::Defs(defs)) => defs,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Reference a marker from a vertex position of a shape, e.g. a `<path>` or `<line>`.
+    ///
+    /// The marker is registered in `<defs>` like with [`Svg::define_id`],
+    /// unless an element with the same `id` is already in the document,
+    /// so presets are defined once however many shapes use them.
+    ///
+    /// Returns the `id` of the marker.
+    pub fn attach_marker<T, M>(&mut self, el: &mut T, position: MarkerPosition, marker: M) -> String
+    where
+        T: AccessAttr,
+        M: Into<Marker>,
+    {
+        let marker = marker.into();
+
+        let id = match marker.attr(&Attr::from("id")) {
+            Some(id) if contains_id(self.children.as_ref(), id) => id.to_string(),
+            _ => self.define_id(marker),
+        };
+
+        el.set_attr(Attr::from(position.as_value()), &format!("url(#{id})"));
+
+        id
+    }
+
+    /// Expose the graphic as a single image to assistive technologies:
+    /// set `role="img"` and label it by a `<title>`, optionally described
+    /// by a `<desc>`.
+    ///
+    /// The `<title>` and `<desc>` are put first, replacing the ones
+    /// referenced by a previous label. Without a description,
+    /// `aria-describedby` is removed.
+    pub fn label_as_image(&mut self, title: &str, description: Option<&str>) -> &mut Self {
+        let labels = [
+            (Attr::from("aria-labelledby"), Some(ElementKind::from(Title::new(&title)))),
+            (Attr::from("aria-describedby"), description.map(|description| Desc::new(&description).into())),
+        ];
+
+        // Looking up by key also sets the attributes without `attr-aria`
+        self.set_attr(Attr::from("role"), "img");
+
+        let mut index = 0;
+
+        for (attr, el) in labels {
+            if let (Some(ids), Some(children)) = (self.pop_attr(&attr), self.children.as_mut()) {
+                let ids: Vec<&str> = ids.split_ascii_whitespace().collect();
+
+                children.retain(|child| !matches!(
+                    child,
+                    ChildKind::Element(el @ (ElementKind::Title(_) | ElementKind::Desc(_)))
+                        if el.attr(&Attr::from("id")).is_some_and(|id| ids.contains(&id))
+                ));
+            }
+
+            let Some(mut el) = el else {
+                continue;
+            };
+
+            let id = ensure_id(&mut el, |id| contains_id(self.children.as_ref(), id));
+
+            self.set_attr(attr, &id);
+            self.children.get_or_insert_with(Vec::new).insert(index, ChildKind::Element(el));
+
+            index += 1;
+        }
+
+        self
+    }
+
+    /// Compute the transform from the user space of the content into
+    /// a viewport of the given size, following `viewBox` and `preserveAspectRatio`.
+    ///
+    /// Returns the identity without `viewBox`, or `None` if `viewBox`
+    /// is empty and nothing would be rendered.
+    pub fn viewport_transform(&self, width: f32, height: f32) -> Option<Matrix> {
+        match &self.view_box {
+            Some(view_box) => view_box.viewport_transform(&self.preserve_aspect_ratio.unwrap_or_default(), width, height),
+            None => Some(Matrix::identity()),
+        }
+    }
+
+    impl_accessor!(primitive:raw -> view_box, set_view_box, ViewBox, "viewBox");
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+    impl_accessor!(primitive:raw -> preserve_aspect_ratio, set_preserve_aspect_ratio, PreserveAspectRatio, "preserveAspectRatio");
 }
 
 impl_element!(Svg, "svg");
 impl_attr_accessors!(Svg);
 
 impl WriteXml for Svg {
-    fn write_xml(&self, writer: &mut quick_xml::Writer<std::io::Cursor<Vec<u8>>>) -> Result<(), crate::error::Error> {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
         let tag = Self::tag_name();
 
         let mut bs = BytesStart::new(tag);
 
-        push_attr!(self.view_box, bs, "viewBox" <- String);
-        push_attr!(self.x, bs, "x" <- String);
-        push_attr!(self.y, bs, "y" <- String);
-        push_attr!(self.width, bs, "width" <- String);
-        push_attr!(self.height, bs, "height" <- String);
-        push_attr!(self.preserve_aspect_ratio, bs, "preserveAspectRatio" <- String);
+        push_attr!(self.view_box, bs, "viewBox" <- Format(options));
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+        push_attr!(self.preserve_aspect_ratio, bs, "preserveAspectRatio" <- ToString);
 
-        push_attr!(map: self.attr, bs);
+        push_attr!(map: self.attr, bs, options);
 
         // Add XML namespace for SVG
         bs.push_attribute(("xmlns", SVG_NAMESPACE));
 
-        convert_into_xml(writer, bs, self.children.as_ref(), tag)
+        convert_into_xml(writer, bs, self.children.as_ref(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Svg {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "viewBox" => pull_attr!(self.view_box, value <- FromStr | map: self, key),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            "preserveAspectRatio" => pull_attr!(self.preserve_aspect_ratio, value <- FromStr | map: self, key),
+            // Written automatically
+            "xmlns" => {}
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl Svg {
+    /// Transform from the user space of the content into the user space
+    /// of the parent, placing the viewport at `x` and `y` and fitting
+    /// `viewBox` into `width` and `height` if all are set.
+    ///
+    /// Returns `None` if a length cannot be resolved or the view box is empty.
+    pub(crate) fn content_matrix(&self) -> Option<Matrix> {
+        let x = self.x.unwrap_or_default().to_absolute_user_units()?;
+        let y = self.y.unwrap_or_default().to_absolute_user_units()?;
+
+        let mut matrix = Matrix::translate(x, y);
+
+        if let (Some(view_box), Some(width), Some(height)) = (self.view_box, self.width, self.height) {
+            let aspect = self.preserve_aspect_ratio.unwrap_or_default();
+            matrix = matrix * view_box.viewport_transform(&aspect, width.to_absolute_user_units()?, height.to_absolute_user_units()?)?;
+        }
+
+        Some(matrix)
+    }
+}
+
+impl BBox for Svg {
+    /// `<use>` elements are resolved against the content of the `<svg>`.
+    fn transformed_bbox(&self, matrix: &Matrix) -> Option<BoundingBox> {
+        let refs = References::collect(self.children.as_ref());
+
+        resolved_children_bbox(self.children.as_ref(), &(local_matrix(self, matrix)? * self.content_matrix()?), &refs)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::element::{Children, ElementKind};
 
     #[test]
     fn basic_svg_element() {
         assert_eq!(Svg::new().to_string(), r#"<svg xmlns="http://www.w3.org/2000/svg"/>"#);
     }
+
+    #[test]
+    fn typed_view_box() {
+        let mut svg: Svg = r#"<svg viewBox="0 0 20 10" preserveAspectRatio="xMaxYMax slice"/>"#.parse().unwrap();
+
+        assert_eq!(svg.view_box(), Some(ViewBox::new(0.0, 0.0, 20.0, 10.0)));
+        assert_eq!(svg.viewport_transform(10.0, 10.0), Some(Matrix::new(1.0, 0.0, 0.0, 1.0, -10.0, 0.0)));
+
+        svg.set_preserve_aspect_ratio(None);
+        assert_eq!(svg.to_string(), r#"<svg viewBox="0 0 20 10" xmlns="http://www.w3.org/2000/svg"/>"#);
+    }
+
+    #[test]
+    fn keep_unparsed_view_box() {
+        let src = r#"<svg viewBox="0 0 10" preserveAspectRatio="middle"><marker orient="sideways" markerUnits="px" viewBox="none"/></svg>"#;
+        let mut svg: Svg = src.parse().unwrap();
+
+        assert_eq!(svg.view_box(), None);
+        assert_eq!(svg.attr(&Attr::from("viewBox")), Some("0 0 10"));
+        assert_eq!(
+            svg.to_string(),
+            r#"<svg viewBox="0 0 10" preserveAspectRatio="middle" xmlns="http://www.w3.org/2000/svg"><marker orient="sideways" markerUnits="px" viewBox="none"/></svg>"#,
+        );
+
+        svg.set_view_box(Some(ViewBox::new(0.0, 0.0, 10.0, 10.0)));
+        assert_eq!(svg.attr(&Attr::from("viewBox")), None);
+        assert!(svg.to_string().starts_with(r#"<svg viewBox="0 0 10 10" preserveAspectRatio="middle" "#));
+    }
+
+    #[test]
+    fn write_to_sinks() {
+        let svg = Svg::with_view_box((0.0, 0.0, 1.0, 1.0));
+        let expected = r#"<svg viewBox="0 0 1 1" xmlns="http://www.w3.org/2000/svg"/>"#;
+
+        let mut out = Vec::new();
+        svg.write_to(&mut out).unwrap();
+        assert_eq!(out, expected.as_bytes());
+
+        let path = std::env::temp_dir().join(format!("svgize-write-to-file-{}.svg", std::process::id()));
+        ElementKind::from(svg).write_to_file(&path).unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(written, expected);
+    }
+
+    #[test]
+    fn define_reused_content() {
+        let mut svg = Svg::new();
+
+        let first = svg.define(crate::element::Circle::new((0, 0), 1));
+        let mut second = svg.define(crate::element::Circle::new((0, 0), 2));
+        second.set_x(Some(10));
+
+        assert_eq!(first.href(), Some("#circle1"));
+
+        svg.children_mut().unwrap().push(ChildKind::Element(first.into()));
+        svg.children_mut().unwrap().push(ChildKind::Element(second.into()));
+
+        assert_eq!(
+            svg.to_string(),
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg"><defs><circle cx="0" cy="0" r="1" id="circle1"/><circle cx="0" cy="0" r="2" id="circle2"/></defs>"#,
+                r##"<use href="#circle1"/><use href="#circle2" x="10"/></svg>"##,
+            ),
+        );
+
+        // Definitions only count where they are used
+        let bbox = svg.bbox().unwrap();
+        assert_eq!((bbox.x(), bbox.y(), bbox.width(), bbox.height()), (-1.0, -2.0, 13.0, 4.0));
+
+        let parsed: Svg = r##"<svg><use xlink:href="#a"/></svg>"##.parse().unwrap();
+        assert_eq!(parsed.to_string(), r##"<svg xmlns="http://www.w3.org/2000/svg"><use xlink:href="#a"/></svg>"##);
+        assert_eq!(parsed.bbox(), None);
+    }
+
+    #[test]
+    fn resolve_symbols_and_cycles() {
+        let src = r##"<svg>
+            <symbol id="icon" viewBox="0 0 10 10"><rect width="10" height="10"/></symbol>
+            <g id="loop"><use href="#loop"/><circle r="1"/></g>
+            <use href="#icon" x="20" width="20" height="20"/>
+        </svg>"##;
+
+        let svg: Svg = src.parse().unwrap();
+        let bbox = svg.bbox().unwrap();
+
+        assert_eq!((bbox.x(), bbox.y(), bbox.width(), bbox.height()), (-1.0, -1.0, 41.0, 21.0));
+    }
+
+    #[test]
+    fn nested_viewport_bbox() {
+        let mut inner = Svg::with_view_box((0.0, 0.0, 10.0, 10.0));
+        inner.set_x(Some(5)).set_y(Some(5)).set_width(Some(20)).set_height(Some(40));
+        inner.children_mut().unwrap().push(ChildKind::Element(crate::element::Rect::new(2, 2, 6, 6).into()));
+
+        // Scaled by 2 to meet the width, then centered vertically
+        let bbox = inner.bbox().unwrap();
+        assert_eq!((bbox.x(), bbox.y(), bbox.width(), bbox.height()), (9.0, 19.0, 12.0, 12.0));
+
+        let mut outer = Svg::new();
+        outer.children_mut().unwrap().push(ChildKind::Element(inner.into()));
+        assert_eq!(outer.fit_view_box(), Some(bbox));
+    }
+
+    #[test]
+    fn label_as_image() {
+        let mut svg = Svg::new();
+        svg.children_mut().unwrap().extend([
+            ChildKind::Element(Desc::new(&"Kept").into()),
+            ChildKind::Element(crate::element::Rect::new(0, 0, 10, 10).into()),
+        ]);
+
+        svg.label_as_image("Old", Some("Old description"));
+        svg.label_as_image("Sales by region", Some("Bar chart of Q3 sales"));
+
+        assert_eq!(
+            svg.to_string(),
+            concat!(
+                r#"<svg role="img" aria-labelledby="title1" aria-describedby="desc1" xmlns="http://www.w3.org/2000/svg">"#,
+                r#"<title id="title1">Sales by region</title><desc id="desc1">Bar chart of Q3 sales</desc>"#,
+                r#"<desc>Kept</desc><rect x="0" y="0" width="10" height="10"/></svg>"#,
+            ),
+        );
+
+        svg.label_as_image("Sales by region", None);
+
+        assert_eq!(
+            svg.to_string(),
+            concat!(
+                r#"<svg role="img" aria-labelledby="title1" xmlns="http://www.w3.org/2000/svg">"#,
+                r#"<title id="title1">Sales by region</title>"#,
+                r#"<desc>Kept</desc><rect x="0" y="0" width="10" height="10"/></svg>"#,
+            ),
+        );
+
+        #[cfg(feature = "attr-aria")]
+        {
+            assert_eq!(svg.role().unwrap().unwrap(), crate::attr::aria::Role::Img);
+            assert_eq!(svg.aria_labelledby(), Some(vec!["title1"]));
+        }
+    }
 }
@@ -0,0 +1,122 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, length::Length, transform::Matrix, view_box::{PreserveAspectRatio, ViewBox}, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Symbol element (`<symbol>`)
+///
+/// A symbol is only rendered when referenced by `<use>`.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/symbol).
+pub struct Symbol {
+    view_box: Option<ViewBox>,
+    preserve_aspect_ratio: Option<PreserveAspectRatio>,
+
+    ref_x: Option<Length>,
+    ref_y: Option<Length>,
+
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Symbol {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            view_box: None,
+            preserve_aspect_ratio: None,
+            ref_x: None,
+            ref_y: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Symbol {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Initialize symbol element with `viewBox` attribute.
+    pub fn with_view_box<T>(view_box: T) -> Self
+    where
+        T: Into<ViewBox>,
+    {
+        let mut symbol = Self::new();
+
+        symbol.set_view_box(Some(view_box.into()));
+
+        symbol
+    }
+
+    impl_accessor!(primitive:raw -> view_box, set_view_box, ViewBox, "viewBox");
+    impl_accessor!(primitive:raw -> preserve_aspect_ratio, set_preserve_aspect_ratio, PreserveAspectRatio, "preserveAspectRatio");
+    impl_accessor!(length -> ref_x, set_ref_x, "refX");
+    impl_accessor!(length -> ref_y, set_ref_y, "refY");
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+}
+
+impl_element!(Symbol, "symbol");
+impl_attr_accessors!(Symbol);
+
+impl WriteXml for Symbol {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.view_box, bs, "viewBox" <- Format(options));
+        push_attr!(self.preserve_aspect_ratio, bs, "preserveAspectRatio" <- ToString);
+        push_attr!(self.ref_x, bs, "refX" <- Format(options));
+        push_attr!(self.ref_y, bs, "refY" <- Format(options));
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Symbol {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "viewBox" => pull_attr!(self.view_box, value <- FromStr | map: self, key),
+            "preserveAspectRatio" => pull_attr!(self.preserve_aspect_ratio, value <- FromStr | map: self, key),
+            "refX" => pull_attr!(self.ref_x, value <- FromStr | map: self, key),
+            "refY" => pull_attr!(self.ref_y, value <- FromStr | map: self, key),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Symbol {
+    /// Symbols are not rendered unless referenced.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
@@ -1,24 +1,24 @@
 use quick_xml::events::BytesStart;
 
-use crate::{attr::{impl_attr_accessors, length_adjust::LengthAdjust, LazyAttrMap}, element::{convert_into_xml, Children}, push_attr};
+use crate::{attr::{impl_attr_accessors, length::Length, length_adjust::LengthAdjust, transform::Matrix, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
 
-use super::{impl_accessor, impl_element, ChildList, TagName, WriteXml};
+use super::{impl_accessor, impl_element, ChildKind, ChildList, ReadXml, TSpan, TagName, WriteXml};
 
 /// Text element (`<text>`)
 ///
 /// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/text).
 pub struct Text {
-    x: Option<String>,
-    y: Option<String>,
+    x: Option<Vec<Length>>,
+    y: Option<Vec<Length>>,
 
-    dx: Option<String>,
-    dy: Option<String>,
+    dx: Option<Vec<Length>>,
+    dy: Option<Vec<Length>>,
 
     rotate: Option<Vec<f32>>,
 
     length_adjust: Option<LengthAdjust>,
 
-    text_length: Option<String>,
+    text_length: Option<Length>,
 
     attr: LazyAttrMap,
 
@@ -27,7 +27,6 @@ pub struct Text {
 
 impl Default for Text {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self {
             x: None,
@@ -45,47 +44,147 @@ impl Default for Text {
 
 impl Text {
     #[inline]
-    pub fn new<TX, TY>(x: &TX, y: &TY) -> Self
+    pub fn new<X, Y>(x: X, y: Y) -> Self
     where
-        TX: ToString,
-        TY: ToString,
+        X: Into<Length>,
+        Y: Into<Length>,
     {
-        let mut t = Self::default();
-        
-        t.set_x(Some(x));
-        t.set_y(Some(y));
+        Self {
+            x: Some(vec![x.into()]),
+            y: Some(vec![y.into()]),
+            ..Default::default()
+        }
+    }
+
+    /// Create a text with one `<tspan>` per line, the first one starting at
+    /// `(x, y)` and each following one moved down by `line_height`, e.g. `1.2em`.
+    ///
+    /// Empty lines are kept as gaps.
+    pub fn multiline<X, Y, I, T, H>(x: X, y: Y, lines: I, line_height: H) -> Self
+    where
+        X: Into<Length>,
+        Y: Into<Length>,
+        I: IntoIterator<Item = T>,
+        T: ToString,
+        H: Into<Length>,
+    {
+        let x = x.into();
+        let line_height = line_height.into();
+
+        let mut text = Self::new(x, y);
+        let mut spans = Vec::new();
+
+        // An empty span has no character to carry its `dy`
+        let mut skipped = 0;
+
+        for line in lines {
+            let line = line.to_string();
 
-        t
+            if line.is_empty() {
+                skipped += 1;
+                continue;
+            }
+
+            let mut span = TSpan::new(&line);
+            span.x_mut().push(x);
+
+            if !spans.is_empty() || skipped > 0 {
+                let lines = if spans.is_empty() { skipped } else { skipped + 1 };
+                span.dy_mut().push(Length::new(line_height.value() * lines as f32, line_height.unit()));
+            }
+
+            skipped = 0;
+            spans.push(ChildKind::Element(span.into()));
+        }
+
+        text.children = Some(spans);
+        text
     }
 
-    impl_accessor!(string* -> x, set_x, "x");
-    impl_accessor!(string* -> y, set_y, "y");
-    impl_accessor!(string* -> dx, set_dx, "dx");
-    impl_accessor!(string* -> dy, set_dy, "dy");
+    impl_accessor!(list:primitive -> x, x_mut, "x", Length);
+    impl_accessor!(list:primitive -> y, y_mut, "y", Length);
+    impl_accessor!(list:primitive -> dx, dx_mut, "dx", Length);
+    impl_accessor!(list:primitive -> dy, dy_mut, "dy", Length);
     impl_accessor!(list:primitive -> rotate, rotate_mut, "rotate", f32);
     impl_accessor!(ref:move_setter -> length_adjust, set_length_adjust, "lengthAdjust", LengthAdjust);
-    impl_accessor!(string* -> text_length, set_text_length, "textLength");
+    impl_accessor!(length -> text_length, set_text_length, "textLength");
 }
 
 impl_element!(Text, "text");
 impl_attr_accessors!(Text);
 
 impl WriteXml for Text {
-    fn write_xml(&self, writer: &mut quick_xml::Writer<std::io::Cursor<Vec<u8>>>) -> Result<(), crate::error::Error> {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
         let tag = Self::tag_name();
 
         let mut bs = BytesStart::new(tag);
 
-        push_attr!(self.x, bs, "x" <- String);
-        push_attr!(self.y, bs, "y" <- String);
-        push_attr!(self.dx, bs, "dx" <- String);
-        push_attr!(self.dy, bs, "dy" <- String);
-        push_attr!(self.rotate, bs, "rotate" <- primitives | " ");
+        push_attr!(self.x, bs, "x" <- Formats(options) | " ");
+        push_attr!(self.y, bs, "y" <- Formats(options) | " ");
+        push_attr!(self.dx, bs, "dx" <- Formats(options) | " ");
+        push_attr!(self.dy, bs, "dy" <- Formats(options) | " ");
+        push_attr!(self.rotate, bs, "rotate" <- Formats(options) | " ");
         push_attr!(self.length_adjust, bs, "lengthAdjust" <- ToString);
-        push_attr!(self.text_length, bs, "textLength" <- String);
+        push_attr!(self.text_length, bs, "textLength" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Text {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "x" => pull_attr!(self.x, value <- primitives | map: self, key),
+            "y" => pull_attr!(self.y, value <- primitives | map: self, key),
+            "dx" => pull_attr!(self.dx, value <- primitives | map: self, key),
+            "dy" => pull_attr!(self.dy, value <- primitives | map: self, key),
+            "rotate" => pull_attr!(self.rotate, value <- primitives),
+            "lengthAdjust" => pull_attr!(self.length_adjust, value <- FromStr),
+            "textLength" => pull_attr!(self.text_length, value <- FromStr | map: self, key),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Text {
+    /// Text metrics are unknown without fonts.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{attr::length::LengthUnit, element::ElementKind};
+
+    use super::*;
+
+    #[test]
+    fn multiline_label() {
+        let text = Text::multiline(10, 20, ["Revenue", "", "2024"], Length::new(1.2, LengthUnit::Em));
+
+        assert_eq!(
+            text.to_string(),
+            r#"<text x="10" y="20"><tspan x="10">Revenue</tspan><tspan x="10" dy="2.4em">2024</tspan></text>"#,
+        );
+    }
+
+    #[test]
+    fn typed_positions() {
+        let ElementKind::Text(mut text) = crate::parser::parse(r#"<text x="1 2em" y="auto" dy="5,-5">A</text>"#).unwrap() else {
+            unreachable!()
+        };
 
-        push_attr!(map: self.attr, bs);
+        assert_eq!(text.x(), Some(&vec![Length::user(1.0), Length::new(2.0, LengthUnit::Em)]));
+        assert_eq!(text.y(), None);
+        assert_eq!(text.to_string(), r#"<text x="1 2em" dy="5 -5" y="auto">A</text>"#);
 
-        convert_into_xml(writer, bs, self.children(), tag)
+        text.y_mut().push(Length::user(3.0));
+        assert_eq!(text.to_string(), r#"<text x="1 2em" y="3" dy="5 -5">A</text>"#);
     }
 }
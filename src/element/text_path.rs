@@ -0,0 +1,160 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, length::Length, length_adjust::LengthAdjust, text_path::{TextPathMethod, TextPathSide, TextPathSpacing}, transform::Matrix, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, path::PathData, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, ChildKind, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Text path element (`<textPath>`), rendering its text along a path
+///
+/// The path is either referenced by `href` or given inline by `path`,
+/// which takes precedence.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/textPath).
+pub struct TextPath {
+    href: Option<String>,
+    xlink: bool,
+    path: Option<PathData>,
+
+    start_offset: Option<Length>,
+    method: Option<TextPathMethod>,
+    spacing: Option<TextPathSpacing>,
+    side: Option<TextPathSide>,
+
+    length_adjust: Option<LengthAdjust>,
+    text_length: Option<Length>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for TextPath {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            href: None,
+            xlink: false,
+            path: None,
+            start_offset: None,
+            method: None,
+            spacing: None,
+            side: None,
+            length_adjust: None,
+            text_length: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl TextPath {
+    /// Render `content` along the `<path>` with the given `id`.
+    pub fn referencing<T>(id: &str, content: &T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            href: Some(format!("#{id}")),
+            children: Some(vec![ChildKind::String(content.to_string())]),
+            ..Default::default()
+        }
+    }
+
+    /// Render `content` along inline path data.
+    pub fn along<T>(path: PathData, content: &T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            path: Some(path),
+            children: Some(vec![ChildKind::String(content.to_string())]),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(href -> href, set_href);
+    impl_accessor!(ref:move_setter -> path, set_path, "path", PathData);
+    impl_accessor!(length -> start_offset, set_start_offset, "startOffset");
+    impl_accessor!(primitive -> method, set_method, TextPathMethod, "method");
+    impl_accessor!(primitive -> spacing, set_spacing, TextPathSpacing, "spacing");
+    impl_accessor!(primitive -> side, set_side, TextPathSide, "side");
+    impl_accessor!(ref:move_setter -> length_adjust, set_length_adjust, "lengthAdjust", LengthAdjust);
+    impl_accessor!(length -> text_length, set_text_length, "textLength");
+}
+
+impl_element!(TextPath, "textPath");
+impl_attr_accessors!(TextPath);
+
+impl WriteXml for TextPath {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(href: self, bs);
+        push_attr!(self.path, bs, "path" <- Format(options));
+        push_attr!(self.start_offset, bs, "startOffset" <- Format(options));
+        push_attr!(self.method, bs, "method" <- ToString);
+        push_attr!(self.spacing, bs, "spacing" <- ToString);
+        push_attr!(self.side, bs, "side" <- ToString);
+        push_attr!(self.length_adjust, bs, "lengthAdjust" <- ToString);
+        push_attr!(self.text_length, bs, "textLength" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for TextPath {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "href" | "xlink:href" => pull_attr!(href: self, key, value),
+            "path" => pull_attr!(self.path, value <- FromStr),
+            "startOffset" => pull_attr!(self.start_offset, value <- FromStr | map: self, key),
+            "method" => pull_attr!(self.method, value <- FromStr),
+            "spacing" => pull_attr!(self.spacing, value <- FromStr),
+            "side" => pull_attr!(self.side, value <- FromStr),
+            "lengthAdjust" => pull_attr!(self.length_adjust, value <- FromStr),
+            "textLength" => pull_attr!(self.text_length, value <- FromStr | map: self, key),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for TextPath {
+    /// Text metrics are unknown without fonts.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{attr::text_path::TextPathSide, element::{ChildKind, Text}};
+
+    use super::*;
+
+    #[test]
+    fn write_and_parse() {
+        let mut path = TextPath::referencing("curve", &"Along & around");
+        path.set_start_offset(Some(Length::percent(50.0)))
+            .set_side(Some(TextPathSide::Right));
+
+        let mut label = Text::new(0, 0);
+        label.children_mut().unwrap().push(ChildKind::Element(path.into()));
+
+        let expected = r##"<text x="0" y="0"><textPath href="#curve" startOffset="50%" side="right">Along &amp; around</textPath></text>"##;
+
+        assert_eq!(label.to_string(), expected);
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+    }
+
+    #[test]
+    fn keep_xlink_href() {
+        let expected = r##"<text><textPath xlink:href="#curve">A</textPath><textPath href="#curve">B</textPath></text>"##;
+        assert_eq!(crate::parser::parse(expected).unwrap().to_string(), expected);
+    }
+}
@@ -0,0 +1,103 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, length::Length, transform::Matrix, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, ChildKind, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Text span element (`<tspan>`)
+///
+/// Positions in `x`, `y`, `dx`, `dy` and `rotate` apply to the characters
+/// in order, and the last one also applies to the remaining characters
+/// for `rotate`.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/tspan).
+pub struct TSpan {
+    x: Option<Vec<Length>>,
+    y: Option<Vec<Length>>,
+
+    dx: Option<Vec<Length>>,
+    dy: Option<Vec<Length>>,
+
+    rotate: Option<Vec<f32>>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for TSpan {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            x: None,
+            y: None,
+            dx: None,
+            dy: None,
+            rotate: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl TSpan {
+    /// Create a span holding `content`.
+    pub fn new<T>(content: &T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            children: Some(vec![ChildKind::String(content.to_string())]),
+            ..Default::default()
+        }
+    }
+
+    impl_accessor!(list:primitive -> x, x_mut, "x", Length);
+    impl_accessor!(list:primitive -> y, y_mut, "y", Length);
+    impl_accessor!(list:primitive -> dx, dx_mut, "dx", Length);
+    impl_accessor!(list:primitive -> dy, dy_mut, "dy", Length);
+    impl_accessor!(list:primitive -> rotate, rotate_mut, "rotate", f32);
+}
+
+impl_element!(TSpan, "tspan");
+impl_attr_accessors!(TSpan);
+
+impl WriteXml for TSpan {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(self.x, bs, "x" <- Formats(options) | " ");
+        push_attr!(self.y, bs, "y" <- Formats(options) | " ");
+        push_attr!(self.dx, bs, "dx" <- Formats(options) | " ");
+        push_attr!(self.dy, bs, "dy" <- Formats(options) | " ");
+        push_attr!(self.rotate, bs, "rotate" <- Formats(options) | " ");
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for TSpan {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "x" => pull_attr!(self.x, value <- primitives | map: self, key),
+            "y" => pull_attr!(self.y, value <- primitives | map: self, key),
+            "dx" => pull_attr!(self.dx, value <- primitives | map: self, key),
+            "dy" => pull_attr!(self.dy, value <- primitives | map: self, key),
+            "rotate" => pull_attr!(self.rotate, value <- primitives),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for TSpan {
+    /// Text metrics are unknown without fonts.
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
@@ -0,0 +1,108 @@
+use quick_xml::events::BytesStart;
+
+use crate::{attr::{impl_attr_accessors, length::Length, transform::Matrix, LazyAttrMap}, bbox::{BBox, BoundingBox}, element::{convert_into_xml, Children}, push_attr, pull_attr, serialize::SerializeOptions};
+
+use super::{impl_accessor, impl_element, LazyChildList, ReadXml, TagName, WriteXml};
+
+/// Use element (`<use>`)
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/use).
+pub struct Use {
+    href: Option<String>,
+    xlink: bool,
+
+    x: Option<Length>,
+    y: Option<Length>,
+    width: Option<Length>,
+    height: Option<Length>,
+
+    attr: LazyAttrMap,
+    children: LazyChildList,
+}
+
+impl Default for Use {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            href: None,
+            xlink: false,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            attr: None,
+            children: None,
+        }
+    }
+}
+
+impl Use {
+    #[inline]
+    pub fn new<T>(href: &T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            href: Some(href.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Reference an element in the same document by its `id`.
+    #[inline]
+    pub fn referencing(id: &str) -> Self {
+        Self::new(&format!("#{id}"))
+    }
+
+    impl_accessor!(href -> href, set_href);
+    impl_accessor!(length -> x, set_x, "x");
+    impl_accessor!(length -> y, set_y, "y");
+    impl_accessor!(length -> width, set_width, "width");
+    impl_accessor!(length -> height, set_height, "height");
+}
+
+impl_element!(Use, "use");
+impl_attr_accessors!(Use);
+
+impl WriteXml for Use {
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>, options: &SerializeOptions, depth: usize) -> Result<(), crate::error::Error> {
+        let tag = Self::tag_name();
+
+        let mut bs = BytesStart::new(tag);
+
+        push_attr!(href: self, bs);
+
+        push_attr!(self.x, bs, "x" <- Format(options));
+        push_attr!(self.y, bs, "y" <- Format(options));
+        push_attr!(self.width, bs, "width" <- Format(options));
+        push_attr!(self.height, bs, "height" <- Format(options));
+
+        push_attr!(map: self.attr, bs, options);
+
+        convert_into_xml(writer, bs, self.children(), tag, options, depth)
+    }
+}
+
+impl ReadXml for Use {
+    fn read_attr(&mut self, key: &str, value: &str) -> Result<(), crate::error::Error> {
+        match key {
+            "href" | "xlink:href" => pull_attr!(href: self, key, value),
+            "x" => pull_attr!(self.x, value <- FromStr | map: self, key),
+            "y" => pull_attr!(self.y, value <- FromStr | map: self, key),
+            "width" => pull_attr!(self.width, value <- FromStr | map: self, key),
+            "height" => pull_attr!(self.height, value <- FromStr | map: self, key),
+            _ => pull_attr!(map: self, key, value),
+        }
+
+        Ok(())
+    }
+}
+
+impl BBox for Use {
+    /// The referenced element cannot be resolved from the `<use>` alone,
+    /// but is when computing the bounding box of the containing [`Svg`](super::Svg).
+    #[inline]
+    fn transformed_bbox(&self, _: &Matrix) -> Option<BoundingBox> {
+        None
+    }
+}
@@ -2,6 +2,35 @@
 pub enum Error {
     NoChildrenExpected,
     NoOptionFound,
+    NoRootElement,
+    UnexpectedEof,
+    UnexpectedElement(String),
+    UnknownElement(String),
+    UnknownMediaType(String),
+    InvalidPathData(usize),
+    InvalidTransform(usize),
+    InvalidLength(usize),
+    InvalidColor(usize),
+    InvalidViewBox(usize),
+    InvalidClockValue(usize),
+    InvalidComment,
+    InvalidProcessingInstruction,
+    XmlReaderError(quick_xml::Error),
     XmlWriterError(quick_xml::Error),
+    IoError(std::io::Error),
     Utf8ParseError(std::str::Utf8Error),
+    ParseFloatError(std::num::ParseFloatError),
+    ParseIntError(std::num::ParseIntError),
+}
+
+impl From<std::num::ParseFloatError> for Error {
+    fn from(value: std::num::ParseFloatError) -> Self {
+        Self::ParseFloatError(value)
+    }
+}
+
+impl From<std::num::ParseIntError> for Error {
+    fn from(value: std::num::ParseIntError) -> Self {
+        Self::ParseIntError(value)
+    }
 }
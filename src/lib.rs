@@ -1,7 +1,13 @@
+use std::{fmt::Display, ops::{Add, Mul, Sub}};
+
 pub mod attr;
+pub mod bbox;
 pub mod constants;
+pub mod document;
 pub mod element;
 pub mod error;
+pub mod parser;
+pub mod serialize;
 
 /// Internal helper macro for appending an attribute
 /// into an XML element.
@@ -18,6 +24,14 @@ macro_rules! push_attr {
         $var.as_ref().inspect(|i| $bs.push_attribute(($attr, i.to_string().as_str())));
     };
 
+    ($var:expr, $bs:ident, $attr:literal <- Format($options:expr)) => {
+        $var.as_ref().inspect(|i| $bs.push_attribute(($attr, crate::serialize::FormatValue::format_value(*i, $options).as_str())));
+    };
+
+    ($var:expr, $bs:ident, $attr:literal <- Formats($options:expr) | $delim:literal) => {
+        $var.as_ref().inspect(|s| $bs.push_attribute(($attr, s.iter().map(|item| crate::serialize::FormatValue::format_value(item, $options)).collect::<Vec<String>>().join($delim).as_str())));
+    };
+
     ($var:expr, $bs:ident, $attr:literal <- strings | $delim:literal) => {
         $var.as_ref().inspect(|s| $bs.push_attribute(($attr, s.join($delim).as_str())));
     };
@@ -26,18 +40,177 @@ macro_rules! push_attr {
         $var.as_ref().inspect(|s| $bs.push_attribute(($attr, s.iter().map(|item| item.to_string()).collect::<Vec<String>>().join($delim).as_str())));
     };
 
-    (map: $var:expr, $bs:ident) => {
+    // `href`, written as `xlink:href` if read so or requested
+    (href: $self:ident, $bs:ident) => {
+        if $self.xlink {
+            push_attr!($self.href, $bs, "xlink:href" <- String);
+        } else {
+            push_attr!($self.href, $bs, "href" <- String);
+        }
+    };
+
+    (map: $var:expr, $bs:ident, $options:expr) => {
         if let Some(attr) = $var.as_ref() {
-            crate::attr::WriteInAttr::write_in(attr, &mut $bs)?;
+            crate::attr::WriteInAttr::write_in(attr, &mut $bs, $options.attr_order)?;
         }
     };
 }
 
 pub(crate) use push_attr;
 
+/// Internal helper macro for reading an attribute value
+/// from XML into an element.
+macro_rules! pull_attr {
+    ($var:expr, $value:ident <- String) => {
+        $var = Some($value.to_string())
+    };
+
+    ($var:expr, $value:ident <- prim) => {
+        $var = Some($value.trim().parse()?)
+    };
+
+    ($var:expr, $value:ident <- FromStr) => {
+        $var = Some($value.parse()?)
+    };
+
+    ($var:expr, $value:ident <- primitives) => {
+        $var = Some(
+            $value.split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|item| !item.is_empty())
+                .map(|item| item.parse())
+                .collect::<Result<Vec<_>, _>>()?
+        )
+    };
+
+    ($var:expr, $value:ident <- primitives | $delim:literal) => {
+        $var = Some(
+            $value.split($delim)
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| item.parse())
+                .collect::<Result<Vec<_>, _>>()?
+        )
+    };
+
+    ($var:expr, $value:ident <- strings | $delim:literal) => {
+        $var = Some(
+            $value.split($delim)
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect()
+        )
+    };
+
+    // Values the typed field cannot hold, e.g. `auto` or `calc(...)` for
+    // a length, are kept as is in the attribute map
+    ($var:expr, $value:ident <- FromStr | map: $self:ident, $key:ident) => {
+        match $value.parse() {
+            Ok(value) => $var = Some(value),
+            Err(_) => {
+                $var = None;
+                pull_attr!(map: $self, $key, $value)
+            }
+        }
+    };
+
+    ($var:expr, $value:ident <- primitives | map: $self:ident, $key:ident) => {
+        match $value.split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|item| !item.is_empty())
+            .map(|item| item.parse())
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(value) => $var = Some(value),
+            Err(_) => {
+                $var = None;
+                pull_attr!(map: $self, $key, $value)
+            }
+        }
+    };
+
+    // Either `href` or `xlink:href`, keeping the form for writing
+    (href: $self:ident, $key:ident, $value:ident) => {{
+        $self.href = Some($value.to_string());
+        $self.xlink = $key == "xlink:href";
+    }};
+
+    (map: $self:ident, $key:ident, $value:ident) => {
+        crate::attr::AccessAttr::set_attr($self, crate::attr::Attr::from($key), $value)
+    };
+}
+
+pub(crate) use pull_attr;
+
 /// Point in a 2D space
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Point(f32, f32);
 
+impl Point {
+    /// Create a point from its coordinates.
+    #[inline]
+    pub fn new(x: f32, y: f32) -> Self {
+        Self(x, y)
+    }
+
+    /// Access the X coordinate.
+    #[inline]
+    pub fn x(&self) -> f32 {
+        self.0
+    }
+
+    /// Access the Y coordinate.
+    #[inline]
+    pub fn y(&self) -> f32 {
+        self.1
+    }
+
+    /// Euclidean distance to another point.
+    pub fn distance(&self, other: &Point) -> f32 {
+        (self.0 - other.0).hypot(self.1 - other.1)
+    }
+
+    /// Linear interpolation towards another point.
+    ///
+    /// `t = 0` gives the current point and `t = 1` gives `other`.
+    pub fn lerp(&self, other: &Point, t: f32) -> Point {
+        Point(self.0 + (other.0 - self.0) * t, self.1 + (other.1 - self.1) * t)
+    }
+}
+
+impl From<(f32, f32)> for Point {
+    #[inline]
+    fn from(value: (f32, f32)) -> Self {
+        Self(value.0, value.1)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Point(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Mul<f32> for Point {
+    type Output = Point;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Point(self.0 * rhs, self.1 * rhs)
+    }
+}
+
 impl PartialEq for Point {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0 && self.1 == other.1
@@ -46,9 +219,9 @@ impl PartialEq for Point {
 
 impl Eq for Point {}
 
-impl ToString for Point {
-    fn to_string(&self) -> String {
-        format!("{},{}", self.0, self.1)
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.0, self.1)
     }
 }
 
@@ -93,13 +266,23 @@ where
     }
 }
 
-impl<T> Into<String> for ValueList<T>
+impl<T> Default for ValueList<T>
+where
+    T: ToString,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<ValueList<T>> for String
 where
     T: ToString,
 {
     #[inline]
-    fn into(self) -> String {
-        self.into_string()
+    fn from(value: ValueList<T>) -> Self {
+        value.into_string()
     }
 }
 
@@ -0,0 +1,311 @@
+use std::str::FromStr;
+
+use quick_xml::{events::{BytesStart, Event}, Reader};
+
+use crate::{document::{Document, PrologItem}, element::{ChildKind, Children, ElementKind, ReadXml, Svg}, error::Error};
+
+pub(crate) mod scanner;
+
+/// Parse an SVG document into an element tree.
+///
+/// Whitespace-only text between elements is dropped, except in text
+/// content elements such as `<text>`, as are comments,
+/// processing instructions and the document type declaration.
+/// See [`parse_document`] to keep them.
+///
+/// Elements are matched by their qualified name. Unsupported elements,
+/// e.g. `<style>` or editor data such as `<sodipodi:namedview>`, are
+/// skipped with their content, unless they are the root element.
+pub fn parse(s: &str) -> Result<ElementKind, Error> {
+    parse_tree(s, |_| Ok(()))
+}
+
+/// Parse an SVG document whose root element must be `<svg>`,
+/// keeping the XML declaration, the document type declaration,
+/// and comments and processing instructions before the root element.
+pub fn parse_document(s: &str) -> Result<Document, Error> {
+    let mut declaration = false;
+    let mut doctype = false;
+    let mut prolog = Vec::new();
+
+    let root = parse_tree(s, |event| {
+        match event {
+            Event::Decl(_) => declaration = true,
+            Event::DocType(_) => doctype = true,
+            Event::Comment(content) => prolog.push(PrologItem::Comment(to_str(content)?.to_string())),
+            Event::PI(content) => {
+                let content = to_str(content)?;
+                let (target, data) = content.split_once(|c: char| c.is_ascii_whitespace()).unwrap_or((content, ""));
+
+                prolog.push(PrologItem::ProcessingInstruction {
+                    target: target.to_string(),
+                    data: data.trim().to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        Ok(())
+    })?;
+
+    let ElementKind::Svg(root) = root else {
+        return Err(Error::UnexpectedElement(root.tag().to_string()));
+    };
+
+    let mut doc = Document::new(root);
+
+    doc.set_declaration(declaration).set_doctype(doctype);
+    *doc.prolog_mut() = prolog;
+
+    Ok(doc)
+}
+
+#[inline]
+fn to_str(bytes: &[u8]) -> Result<&str, Error> {
+    std::str::from_utf8(bytes).map_err(Error::Utf8ParseError)
+}
+
+/// Build the element tree, passing prolog events before the root element
+/// to `on_prolog`.
+fn parse_tree<F>(s: &str, mut on_prolog: F) -> Result<ElementKind, Error>
+where
+    F: FnMut(&Event) -> Result<(), Error>,
+{
+    let mut reader = Reader::from_str(s);
+
+    let mut stack: Vec<ElementKind> = Vec::new();
+    let mut root: Option<ElementKind> = None;
+
+    loop {
+        match reader.read_event().map_err(Error::XmlReaderError)? {
+            Event::Start(bs) => {
+                let Some(mut el) = read_element(&bs, stack.is_empty() && root.is_none())? else {
+                    reader.read_to_end(bs.name()).map_err(Error::XmlReaderError)?;
+                    continue;
+                };
+
+                // Foreign content and metadata are kept as raw markup
+                match el {
+                    ElementKind::ForeignObject(ref mut inner) => {
+                        let content = reader.read_text(bs.name()).map_err(Error::XmlReaderError)?;
+                        inner.set_content(Some(content.as_ref()))?;
+
+                        append_element(&mut stack, &mut root, el)?;
+                    }
+                    ElementKind::Metadata(ref mut inner) => {
+                        let content = reader.read_text(bs.name()).map_err(Error::XmlReaderError)?;
+                        inner.set_content(Some(content.as_ref()))?;
+
+                        append_element(&mut stack, &mut root, el)?;
+                    }
+                    _ => stack.push(el),
+                }
+            }
+            Event::Empty(bs) => {
+                if let Some(el) = read_element(&bs, stack.is_empty() && root.is_none())? {
+                    append_element(&mut stack, &mut root, el)?;
+                }
+            }
+            Event::End(_) => {
+                // Tag names are already matched by the reader
+                let el = stack.pop().ok_or(Error::UnexpectedEof)?;
+                append_element(&mut stack, &mut root, el)?;
+            }
+            Event::Text(text) => {
+                let content = text.unescape().map_err(Error::XmlReaderError)?;
+                append_text(&mut stack, content.as_ref())?;
+            }
+            Event::CData(cdata) => {
+                let content = cdata.into_inner();
+                append_text(&mut stack, std::str::from_utf8(&content).map_err(Error::Utf8ParseError)?)?;
+            }
+            Event::Eof => break,
+            // Anything else before the root element belongs to the prolog
+            event if stack.is_empty() && root.is_none() => on_prolog(&event)?,
+            _ => {}
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err(Error::UnexpectedEof);
+    }
+
+    root.ok_or(Error::NoRootElement)
+}
+
+/// Read an element with its attributes.
+///
+/// Returns `None` if the element is not supported, which is an error
+/// only for the root element.
+fn read_element(bs: &BytesStart, is_root: bool) -> Result<Option<ElementKind>, Error> {
+    let name = bs.name();
+    let tag = std::str::from_utf8(name.as_ref()).map_err(Error::Utf8ParseError)?;
+
+    // Prefixed names belong to other namespaces, e.g. `<rdf:RDF>`
+    let Some(mut el) = ElementKind::from_tag_name(tag) else {
+        return match is_root {
+            true => Err(Error::UnknownElement(tag.to_string())),
+            false => Ok(None),
+        };
+    };
+
+    for attr in bs.attributes() {
+        let attr = attr.map_err(|err| Error::XmlReaderError(err.into()))?;
+
+        let key = std::str::from_utf8(attr.key.as_ref()).map_err(Error::Utf8ParseError)?;
+        let value = attr.unescape_value().map_err(Error::XmlReaderError)?;
+
+        el.read_attr(key, value.as_ref())?;
+    }
+
+    Ok(Some(el))
+}
+
+/// Check that raw markup, e.g. the content of `<foreignObject>`,
+/// is well-formed: tags are balanced and references are defined.
+pub(crate) fn check_markup(s: &str) -> Result<(), Error> {
+    let mut reader = Reader::from_str(s);
+    let mut depth = 0usize;
+
+    loop {
+        match reader.read_event().map_err(Error::XmlReaderError)? {
+            Event::Start(bs) => {
+                check_attributes(&bs)?;
+                depth += 1;
+            }
+            Event::Empty(bs) => check_attributes(&bs)?,
+            // Unmatched end tags are reported by the reader
+            Event::End(_) => depth -= 1,
+            Event::Text(text) => {
+                text.unescape().map_err(Error::XmlReaderError)?;
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    match depth {
+        0 => Ok(()),
+        _ => Err(Error::UnexpectedEof),
+    }
+}
+
+fn check_attributes(bs: &BytesStart) -> Result<(), Error> {
+    for attr in bs.attributes() {
+        let attr = attr.map_err(|err| Error::XmlReaderError(err.into()))?;
+        attr.unescape_value().map_err(Error::XmlReaderError)?;
+    }
+
+    Ok(())
+}
+
+fn append_element(stack: &mut [ElementKind], root: &mut Option<ElementKind>, el: ElementKind) -> Result<(), Error> {
+    if let Some(parent) = stack.last_mut() {
+        parent.children_mut()?.push(ChildKind::Element(el));
+    } else if root.is_none() {
+        *root = Some(el);
+    } else {
+        return Err(Error::UnexpectedElement(el.tag().to_string()));
+    }
+
+    Ok(())
+}
+
+fn append_text(stack: &mut [ElementKind], content: &str) -> Result<(), Error> {
+    // Text outside of the root element is ignored
+    let Some(parent) = stack.last_mut() else {
+        return Ok(());
+    };
+
+    // Whitespace is only significant in text content, e.g. between `<tspan>`s
+    let is_text = matches!(parent, ElementKind::Text(_) | ElementKind::TSpan(_) | ElementKind::TextPath(_));
+
+    if is_text || !content.trim().is_empty() {
+        parent.children_mut()?.push(ChildKind::String(content.to_string()));
+    }
+
+    Ok(())
+}
+
+impl FromStr for ElementKind {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+impl FromStr for Svg {
+    type Err = Error;
+
+    /// Parse an SVG document whose root element must be `<svg>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse(s)? {
+            ElementKind::Svg(svg) => Ok(svg),
+            el => Err(Error::UnexpectedElement(el.tag().to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_nested_elements() {
+        let src = r#"<svg viewBox="0 0 10 10" xmlns="http://www.w3.org/2000/svg">
+            <g>
+                <rect x="1" y="2" width="3" height="4"/>
+                <text x="0" y="5">Hello &amp; bye</text>
+            </g>
+        </svg>"#;
+
+        let svg: Svg = src.parse().unwrap();
+
+        assert_eq!(
+            svg.to_string(),
+            r#"<svg viewBox="0 0 10 10" xmlns="http://www.w3.org/2000/svg"><g><rect x="1" y="2" width="3" height="4"/><text x="0" y="5">Hello &amp; bye</text></g></svg>"#,
+        );
+    }
+
+    #[test]
+    fn parse_attr_map() {
+        let el = parse(r#"<circle cx="1" cy="2" r="3" stroke="red"/>"#).unwrap();
+        assert_eq!(el.to_string(), r#"<circle cx="1" cy="2" r="3" stroke="red"/>"#);
+    }
+
+    #[test]
+    fn keep_whitespace_in_text() {
+        let src = r#"<svg xmlns="http://www.w3.org/2000/svg"><text><tspan>A</tspan> <tspan>B</tspan></text></svg>"#;
+        assert_eq!(parse(src).unwrap().to_string(), src);
+
+        let src = "<svg>\n    <g>\n        <text> A </text>\n    </g>\n</svg>";
+        assert_eq!(parse(src).unwrap().to_string(), r#"<svg xmlns="http://www.w3.org/2000/svg"><g><text> A </text></g></svg>"#);
+    }
+
+    #[test]
+    fn skip_unsupported_elements() {
+        let src = r#"<svg xmlns:sodipodi="http://sodipodi.sourceforge.net/DTD/sodipodi-0.dtd">
+            <style>rect { fill: red; }</style>
+            <sodipodi:namedview pagecolor="white"><inkscape:grid/></sodipodi:namedview>
+            <foo:rect width="1" height="1"/>
+            <blink/>
+            <rect width="2" height="2"/>
+        </svg>"#;
+
+        assert_eq!(
+            parse(src).unwrap().to_string(),
+            r#"<svg xmlns:sodipodi="http://sodipodi.sourceforge.net/DTD/sodipodi-0.dtd" xmlns="http://www.w3.org/2000/svg"><rect width="2" height="2"/></svg>"#,
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(parse(r#"<blink><svg/></blink>"#), Err(Error::UnknownElement(tag)) if tag == "blink"));
+        assert!(matches!(parse(r#"<svg><g></svg>"#), Err(Error::XmlReaderError(_))));
+        assert!(matches!(parse(r#"<svg><g>"#), Err(Error::UnexpectedEof)));
+        assert!(matches!(parse(r#"<path d="M 0 0">text</path>"#), Err(Error::NoChildrenExpected)));
+        assert!(matches!("<rect/>".parse::<Svg>(), Err(Error::UnexpectedElement(_))));
+    }
+}
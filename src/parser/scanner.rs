@@ -0,0 +1,155 @@
+use crate::error::Error;
+
+/// Cursor over attribute value micro-syntaxes
+///
+/// Shared by the number-based grammars such as path data and transform lists.
+pub(crate) struct Scanner<'a> {
+    src: &'a str,
+    pos: usize,
+    on_error: fn(usize) -> Error,
+}
+
+impl<'a> Scanner<'a> {
+    /// Create a scanner which reports errors through `on_error`
+    /// with the byte offset where scanning has failed.
+    pub(crate) fn new(src: &'a str, on_error: fn(usize) -> Error) -> Self {
+        Self { src, pos: 0, on_error }
+    }
+
+    #[inline]
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.src.as_bytes().get(self.pos).copied()
+    }
+
+    #[inline]
+    pub(crate) fn pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    pub(crate) fn bump(&mut self) {
+        self.pos += 1;
+    }
+
+    #[inline]
+    pub(crate) fn error(&self) -> Error {
+        (self.on_error)(self.pos)
+    }
+
+    /// Consume the expected byte.
+    pub(crate) fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Consume an identifier made of ASCII letters.
+    pub(crate) fn ident(&mut self) -> &'a str {
+        let start = self.pos;
+
+        while matches!(self.peek(), Some(b'a'..=b'z' | b'A'..=b'Z')) {
+            self.pos += 1;
+        }
+
+        &self.src[start..self.pos]
+    }
+
+    pub(crate) fn skip_wsp(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skip optional whitespace with at most one comma.
+    ///
+    /// Returns `true` if a comma has been skipped.
+    pub(crate) fn skip_comma_wsp(&mut self) -> bool {
+        self.skip_wsp();
+
+        if self.peek() == Some(b',') {
+            self.pos += 1;
+            self.skip_wsp();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn starts_number(&self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'-' | b'+'))
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.pos;
+
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+
+        self.pos - start
+    }
+
+    /// Consume a number, which may be as compact as `.5` or `-1e-3`.
+    pub(crate) fn number(&mut self) -> Result<f32, Error> {
+        let start = self.pos;
+
+        if matches!(self.peek(), Some(b'-' | b'+')) {
+            self.pos += 1;
+        }
+
+        let mut digits = self.skip_digits();
+
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            digits += self.skip_digits();
+        }
+
+        if digits == 0 {
+            self.pos = start;
+            return Err(self.error());
+        }
+
+        // Only treat `e` as an exponent if digits follow
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let mantissa_end = self.pos;
+            self.pos += 1;
+
+            if matches!(self.peek(), Some(b'-' | b'+')) {
+                self.pos += 1;
+            }
+
+            if self.skip_digits() == 0 {
+                self.pos = mantissa_end;
+            }
+        }
+
+        self.src[start..self.pos].parse().map_err(|_| (self.on_error)(start))
+    }
+
+    /// Consume a single-character flag (`0` or `1`).
+    pub(crate) fn flag(&mut self) -> Result<bool, Error> {
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(self.error()),
+        };
+
+        self.pos += 1;
+        Ok(flag)
+    }
+
+    /// Read the next number, with an optional comma before it.
+    pub(crate) fn next_number(&mut self) -> Result<f32, Error> {
+        self.skip_comma_wsp();
+        self.number()
+    }
+
+    /// Read the next flag, with an optional comma before it.
+    pub(crate) fn next_flag(&mut self) -> Result<bool, Error> {
+        self.skip_comma_wsp();
+        self.flag()
+    }
+}
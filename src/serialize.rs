@@ -0,0 +1,378 @@
+use std::io::Write;
+
+use quick_xml::Writer;
+
+use crate::{attr::{length::Length, view_box::ViewBox, AttrOrder}, error::Error, Point};
+
+/// Indentation of nested elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Indent {
+    /// No indentation
+    #[default]
+    None,
+    /// Given number of spaces per level
+    Spaces(usize),
+    /// One tab per level
+    Tabs,
+}
+
+/// Precision of emitted numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Precision {
+    /// Shortest form which reads back to the same `f32`
+    #[default]
+    Shortest,
+    /// At most the given number of decimal places
+    Decimals(usize),
+    /// At most the given number of significant digits
+    Significant(usize),
+}
+
+/// Formatting of emitted numbers
+///
+/// Trailing zeros are always stripped, and numbers never use exponents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NumberFormat {
+    pub precision: Precision,
+    /// Write `0.5` as `.5`.
+    pub strip_leading_zero: bool,
+}
+
+impl NumberFormat {
+    #[inline]
+    pub fn new(precision: Precision, strip_leading_zero: bool) -> Self {
+        Self { precision, strip_leading_zero }
+    }
+
+    /// Format a number.
+    pub fn format(&self, value: f32) -> String {
+        let mut out = match self.precision {
+            Precision::Shortest => value.to_string(),
+            Precision::Decimals(decimals) => format_decimals(value as f64, decimals),
+            Precision::Significant(digits) => {
+                let value = value as f64;
+
+                if value == 0.0 || !value.is_finite() {
+                    format_decimals(value, 0)
+                } else {
+                    let magnitude = value.abs().log10().floor() as i32;
+                    let decimals = digits.max(1) as i32 - 1 - magnitude;
+
+                    if decimals >= 0 {
+                        format_decimals(value, decimals as usize)
+                    } else {
+                        let factor = 10f64.powi(-decimals);
+                        format_decimals((value / factor).round() * factor, 0)
+                    }
+                }
+            }
+        };
+
+        if self.strip_leading_zero {
+            if out.starts_with("0.") {
+                out.remove(0);
+            } else if out.starts_with("-0.") {
+                out.remove(1);
+            }
+        }
+
+        out
+    }
+}
+
+fn format_decimals(value: f64, decimals: usize) -> String {
+    let mut out = format!("{value:.decimals$}");
+
+    if out.contains('.') {
+        out.truncate(out.trim_end_matches('0').trim_end_matches('.').len());
+    }
+
+    // Rounding may leave a negative zero
+    if out == "-0" {
+        out.remove(0);
+    }
+
+    out
+}
+
+/// Join numbers with as few separators as possible, i.e. only where
+/// a number would otherwise run into the previous one.
+pub(crate) fn join_compact(out: &mut String, number: &str) {
+    let needs_separator = out.ends_with(|c: char| c.is_ascii_digit() || c == '.')
+        && !number.starts_with('-')
+        && !(number.starts_with('.') && out.rsplit(|c: char| !c.is_ascii_digit() && c != '.').next().is_some_and(|last| last.contains('.')));
+
+    if needs_separator {
+        out.push(' ');
+    }
+
+    out.push_str(number);
+}
+
+/// Instance written as an attribute value according to [`SerializeOptions`].
+pub trait FormatValue {
+    fn format_value(&self, options: &SerializeOptions) -> String;
+}
+
+impl FormatValue for f32 {
+    #[inline]
+    fn format_value(&self, options: &SerializeOptions) -> String {
+        options.numbers.format(*self)
+    }
+}
+
+impl FormatValue for Point {
+    fn format_value(&self, options: &SerializeOptions) -> String {
+        format!("{},{}", options.numbers.format(self.0), options.numbers.format(self.1))
+    }
+}
+
+impl FormatValue for Length {
+    fn format_value(&self, options: &SerializeOptions) -> String {
+        format!("{}{}", options.numbers.format(self.value()), self.unit())
+    }
+}
+
+impl FormatValue for ViewBox {
+    fn format_value(&self, options: &SerializeOptions) -> String {
+        [self.min_x, self.min_y, self.width, self.height]
+            .map(|value| options.numbers.format(value))
+            .join(" ")
+    }
+}
+
+/// Options for writing elements as XML
+///
+/// The default writes everything on a single line, as compact as possible.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SerializeOptions {
+    /// Indentation of children, only applied with `newlines`.
+    pub indent: Indent,
+    /// Put every child on its own line.
+    pub newlines: bool,
+    /// Keep elements having any text child on a single line along with
+    /// their descendants, which also leaves the text untouched.
+    ///
+    /// The content of `<text>`, `<tspan>` and `<textPath>` always stays
+    /// on a single line.
+    pub inline_text: bool,
+    /// Order of the attributes.
+    pub attr_order: AttrOrder,
+    /// Formatting of numbers in typed attributes.
+    pub numbers: NumberFormat,
+    /// Write path data without redundant separators and command letters.
+    pub compact_paths: bool,
+}
+
+impl SerializeOptions {
+    /// Compact single-line output.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Readable output with one child per line and two-space indentation.
+    #[inline]
+    pub fn pretty() -> Self {
+        Self {
+            indent: Indent::Spaces(2),
+            newlines: true,
+            inline_text: true,
+            attr_order: AttrOrder::default(),
+            numbers: NumberFormat::default(),
+            compact_paths: false,
+        }
+    }
+
+    /// Set indentation.
+    #[inline]
+    pub fn indent(mut self, indent: Indent) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Set whether children are put on their own lines.
+    #[inline]
+    pub fn newlines(mut self, newlines: bool) -> Self {
+        self.newlines = newlines;
+        self
+    }
+
+    /// Set whether elements having text children stay on a single line.
+    #[inline]
+    pub fn inline_text(mut self, inline_text: bool) -> Self {
+        self.inline_text = inline_text;
+        self
+    }
+
+    /// Set the order of attributes.
+    #[inline]
+    pub fn attr_order(mut self, attr_order: AttrOrder) -> Self {
+        self.attr_order = attr_order;
+        self
+    }
+
+    /// Set formatting of numbers.
+    #[inline]
+    pub fn numbers(mut self, numbers: NumberFormat) -> Self {
+        self.numbers = numbers;
+        self
+    }
+
+    /// Set whether path data is written in its most compact form.
+    #[inline]
+    pub fn compact_paths(mut self, compact_paths: bool) -> Self {
+        self.compact_paths = compact_paths;
+        self
+    }
+
+    /// Start a new line indented to `depth`, if newlines are enabled.
+    pub(crate) fn write_break<W: Write>(&self, writer: &mut Writer<W>, depth: usize) -> Result<(), Error> {
+        if !self.newlines {
+            return Ok(());
+        }
+
+        let out = writer.get_mut();
+
+        out.write_all(b"\n").map_err(Error::IoError)?;
+
+        match self.indent {
+            Indent::None => Ok(()),
+            Indent::Spaces(n) => out.write_all(" ".repeat(n * depth).as_bytes()).map_err(Error::IoError),
+            Indent::Tabs => out.write_all("\t".repeat(depth).as_bytes()).map_err(Error::IoError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::element::{Svg, TryToString};
+
+    use super::*;
+
+    const SRC: &str = r#"<svg><g><rect width="1" height="1"/><text>Hi</text></g><g></g></svg>"#;
+
+    #[test]
+    fn pretty_print() {
+        let svg: Svg = SRC.parse().unwrap();
+
+        assert_eq!(
+            svg.try_to_string_with(&SerializeOptions::pretty()).unwrap(),
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\">\n",
+                "  <g>\n",
+                "    <rect width=\"1\" height=\"1\"/>\n",
+                "    <text>Hi</text>\n",
+                "  </g>\n",
+                "  <g/>\n",
+                "</svg>",
+            ),
+        );
+
+        let options = SerializeOptions::pretty().indent(Indent::Tabs).inline_text(false);
+
+        assert_eq!(
+            svg.try_to_string_with(&options).unwrap(),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\">\n\t<g>\n\t\t<rect width=\"1\" height=\"1\"/>\n\t\t<text>Hi</text>\n\t</g>\n\t<g/>\n</svg>",
+        );
+    }
+
+    #[test]
+    fn keep_text_inline() {
+        let src = r#"<svg><text>A<tspan>B</tspan>C</text><text><a><tspan>1</tspan></a><tspan>2</tspan></text><desc>D</desc></svg>"#;
+        let svg: Svg = src.parse().unwrap();
+
+        assert_eq!(
+            svg.try_to_string_with(&SerializeOptions::pretty()).unwrap(),
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\">\n",
+                "  <text>A<tspan>B</tspan>C</text>\n",
+                "  <text><a><tspan>1</tspan></a><tspan>2</tspan></text>\n",
+                "  <desc>D</desc>\n",
+                "</svg>",
+            ),
+        );
+
+        assert_eq!(
+            svg.try_to_string_with(&SerializeOptions::pretty().inline_text(false)).unwrap(),
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\">\n",
+                "  <text>A<tspan>B</tspan>C</text>\n",
+                "  <text><a><tspan>1</tspan></a><tspan>2</tspan></text>\n",
+                "  <desc>\n    D\n  </desc>\n",
+                "</svg>",
+            ),
+        );
+    }
+
+    #[test]
+    fn compact_by_default() {
+        let svg: Svg = SRC.parse().unwrap();
+        assert_eq!(svg.try_to_string().unwrap(), svg.try_to_string_with(&SerializeOptions::new().indent(Indent::Spaces(4))).unwrap());
+    }
+
+    #[test]
+    fn attribute_order() {
+        let src = r#"<g stroke="red" data-x="1" id="a" fill="blue"/>"#;
+        let el = crate::parser::parse(src).unwrap();
+
+        assert_eq!(el.try_to_string().unwrap(), src);
+        // Attributes of disabled features are sorted last, like custom ones
+        #[cfg(all(feature = "attr-core", feature = "attr-presentation"))]
+        assert_eq!(
+            el.try_to_string_with(&SerializeOptions::new().attr_order(AttrOrder::Spec)).unwrap(),
+            r#"<g id="a" fill="blue" stroke="red" data-x="1"/>"#,
+        );
+        assert_eq!(
+            el.try_to_string_with(&SerializeOptions::new().attr_order(AttrOrder::Alphabetical)).unwrap(),
+            r#"<g data-x="1" fill="blue" id="a" stroke="red"/>"#,
+        );
+
+        // Attributes specific to the element are sorted along
+        let el = crate::parser::parse(r#"<rect stroke="red" y="2" x="1" id="a" width="3" height="4"/>"#).unwrap();
+
+        assert_eq!(el.try_to_string().unwrap(), r#"<rect x="1" y="2" width="3" height="4" stroke="red" id="a"/>"#);
+        assert_eq!(
+            el.try_to_string_with(&SerializeOptions::new().attr_order(AttrOrder::Alphabetical)).unwrap(),
+            r#"<rect height="4" id="a" stroke="red" width="3" x="1" y="2"/>"#,
+        );
+        #[cfg(all(feature = "attr-core", feature = "attr-presentation"))]
+        assert_eq!(
+            el.try_to_string_with(&SerializeOptions::new().attr_order(AttrOrder::Spec)).unwrap(),
+            r#"<rect id="a" stroke="red" height="4" width="3" x="1" y="2"/>"#,
+        );
+    }
+
+    #[test]
+    fn number_format() {
+        let decimals = NumberFormat::new(Precision::Decimals(2), false);
+        assert_eq!(decimals.format(0.1 + 0.2), "0.3");
+        assert_eq!(decimals.format(1e2), "100");
+        assert_eq!(decimals.format(-0.001), "0");
+        assert_eq!(decimals.format(2.675), "2.67");
+
+        let significant = NumberFormat::new(Precision::Significant(3), true);
+        assert_eq!(significant.format(0.123456), ".123");
+        assert_eq!(significant.format(-0.5), "-.5");
+        assert_eq!(significant.format(123456.0), "123000");
+        assert_eq!(significant.format(1.0), "1");
+
+        assert_eq!(NumberFormat::default().format(1e-7), "0.0000001");
+    }
+
+    #[test]
+    fn compact_path_data() {
+        let el = crate::parser::parse(r#"<path d="M 0.5,0.25 L -1,0.5 L 0.5,0.5 m 1,1 l 2,2 Z A 10 10 0 0 1 .5,-.5"/>"#).unwrap();
+
+        let options = SerializeOptions::new()
+            .numbers(NumberFormat::new(Precision::Shortest, true))
+            .compact_paths(true);
+
+        assert_eq!(el.try_to_string_with(&options).unwrap(), r#"<path d="M.5.25-1 .5.5.5m1 1 2 2ZA10 10 0 0 1 .5-.5"/>"#);
+        assert_eq!(crate::parser::parse(&el.try_to_string_with(&options).unwrap()).unwrap().to_string(), el.to_string());
+
+        let rect = crate::element::Rect::new(0.3333333, 1, 2.5, 2);
+        let options = SerializeOptions::new().numbers(NumberFormat::new(Precision::Decimals(1), false));
+        assert_eq!(rect.try_to_string_with(&options).unwrap(), r#"<rect x="0.3" y="1" width="2.5" height="2"/>"#);
+    }
+}